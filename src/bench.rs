@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use super::error::AocError;
+use super::runner::{read_input, run_day, RunOptions};
+
+/// Stage of solving a day's puzzle that is timed separately.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    /// Parsing the raw input with the day's generator.
+    Parse,
+    /// Solving the given part of the puzzle.
+    Part(u32),
+}
+
+impl Stage {
+    /// Parses the stage from its text form, as produced by `Display`.
+    pub fn from_string(input: &str) -> Option<Stage> {
+        match input {
+            "parse" => return Some(Stage::Parse),
+            "part1" => return Some(Stage::Part(1)),
+            "part2" => return Some(Stage::Part(2)),
+            _ => return None,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part(part) => f.pad(&format!("part{}", part)),
+        }
+    }
+}
+
+/// Summary of the time taken over several runs of the same stage.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TimingStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl TimingStats {
+    /// Summarises the given samples. Panics if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len() % 2 == 0 {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        Self {
+            min: sorted[0],
+            median: median,
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+/// Timings for one stage of a day's puzzle.
+pub struct StageBenchmark {
+    pub day: u32,
+    pub stage: Stage,
+    pub stats: TimingStats,
+}
+
+/// Times the generator and both parts of the day's puzzle over the given number of runs (at least
+/// one), each run with the given options. Stops at the first error, since the timings would not
+/// be comparable. Parts without a solution yet are left out.
+pub fn bench_day(
+    day: u32,
+    raw_input: &str,
+    runs: usize,
+    options: &RunOptions,
+) -> Result<Vec<StageBenchmark>, AocError> {
+    let mut samples: BTreeMap<Stage, Vec<Duration>> = BTreeMap::new();
+    for _ in 0..runs.max(1) {
+        let outcome = run_day(day, &[1, 2], raw_input, options);
+        samples.entry(Stage::Parse).or_default().push(outcome.parse_time);
+        for part_outcome in outcome.parts? {
+            match part_outcome.answer {
+                Ok(_) => (),
+                Err(AocError::NotImplemented(_, _)) => continue,
+                Err(err) => return Err(err),
+            }
+            samples
+                .entry(Stage::Part(part_outcome.part))
+                .or_default()
+                .push(part_outcome.solve_time);
+        }
+    }
+    return Ok(samples
+        .iter()
+        .map(|(stage, stage_samples)| StageBenchmark {
+            day: day,
+            stage: *stage,
+            stats: TimingStats::from_samples(stage_samples),
+        })
+        .collect::<Vec<StageBenchmark>>());
+}
+
+/// Median timings recorded from an earlier benchmark run, used to spot performance regressions.
+///
+/// Stored as text, with one `<day> <stage> <median nanoseconds>` line for each stage. Blank lines
+/// and lines starting with `#` are ignored.
+#[derive(Clone, Debug, Default)]
+pub struct Baseline {
+    medians: BTreeMap<(u32, Stage), Duration>,
+}
+
+impl Baseline {
+    /// Creates a new empty baseline.
+    pub fn new() -> Self {
+        Self {
+            medians: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a baseline holding the median timings from the benchmarks.
+    pub fn from_benchmarks(benchmarks: &[StageBenchmark]) -> Self {
+        let mut baseline = Baseline::new();
+        for benchmark in benchmarks {
+            baseline.insert_median(benchmark.day, benchmark.stage, benchmark.stats.median);
+        }
+        return baseline;
+    }
+
+    /// Loads the baseline from the given file.
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        return Baseline::parse(&read_input(path)?);
+    }
+
+    /// Parses the baseline from its text form.
+    pub fn parse(text: &str) -> Result<Self, AocError> {
+        let mut baseline = Baseline::new();
+        for (line_num, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            let (day, stage, median) = match fields.as_slice() {
+                [day, stage, median] => (day.parse::<u32>(), Stage::from_string(stage), median.parse::<u64>()),
+                _ => return Err(AocError::InvalidBaselineLine(line_num + 1, line.to_string())),
+            };
+            match (day, stage, median) {
+                (Ok(day), Some(stage), Ok(median)) => {
+                    baseline.insert_median(day, stage, Duration::from_nanos(median))
+                }
+                _ => return Err(AocError::InvalidBaselineLine(line_num + 1, line.to_string())),
+            }
+        }
+        return Ok(baseline);
+    }
+
+    /// Saves the baseline to the given file, replacing any existing file.
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let mut text = String::from("# Benchmark baseline: median time in nanoseconds for each day and stage.\n");
+        for ((day, stage), median) in self.medians.iter() {
+            text.push_str(&format!("{} {} {}\n", day, stage, median.as_nanos()));
+        }
+        fs::write(path, text)?;
+        return Ok(());
+    }
+
+    /// Records the median timing for the given day and stage, replacing any existing timing.
+    pub fn insert_median(&mut self, day: u32, stage: Stage, median: Duration) {
+        self.medians.insert((day, stage), median);
+    }
+
+    /// Gets the median timing recorded for the given day and stage.
+    pub fn get_median(&self, day: u32, stage: Stage) -> Option<Duration> {
+        return self.medians.get(&(day, stage)).copied();
+    }
+}
+
+/// Checks if the current timing is slower than the baseline timing by more than the threshold,
+/// given as a percentage of the baseline timing.
+pub fn is_regression(current: Duration, baseline: Duration, threshold_percent: f64) -> bool {
+    return current.as_secs_f64() > baseline.as_secs_f64() * (1.0 + threshold_percent / 100.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timing_stats() {
+        let samples = [5, 1, 4, 2].iter().map(|x| Duration::from_millis(*x)).collect::<Vec<Duration>>();
+        let stats = TimingStats::from_samples(&samples);
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(3), stats.median);
+        assert_eq!(Duration::from_millis(5), stats.max);
+        let stats = TimingStats::from_samples(&samples[0..3]);
+        assert_eq!(Duration::from_millis(4), stats.median);
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let options = RunOptions::default();
+        let benchmarks = bench_day(1, "(()))", 3, &options).unwrap();
+        let stages = benchmarks.iter().map(|x| x.stage).collect::<Vec<Stage>>();
+        assert_eq!(vec![Stage::Parse, Stage::Part(1), Stage::Part(2)], stages);
+        assert!(bench_day(1, "(x", 3, &options).is_err());
+        // Only the generator is timed for a day without any solved parts
+        let benchmarks = bench_day(11, "abcdefgh", 1, &options).unwrap();
+        assert_eq!(vec![Stage::Parse], benchmarks.iter().map(|x| x.stage).collect::<Vec<Stage>>());
+    }
+
+    #[test]
+    fn test_baseline() {
+        let baseline = Baseline::parse("# Baseline\n1 parse 1500\n1 part2 2000000\n").unwrap();
+        assert_eq!(Some(Duration::from_nanos(1500)), baseline.get_median(1, Stage::Parse));
+        assert_eq!(Some(Duration::from_millis(2)), baseline.get_median(1, Stage::Part(2)));
+        assert_eq!(None, baseline.get_median(1, Stage::Part(1)));
+        assert!(Baseline::parse("1 part3 1500").is_err());
+        assert!(is_regression(Duration::from_millis(13), Duration::from_millis(10), 20.0));
+        assert!(!is_regression(Duration::from_millis(11), Duration::from_millis(10), 20.0));
+    }
+}
@@ -0,0 +1,561 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+use advent_of_code_2015::bench::{bench_day, is_regression, Baseline, StageBenchmark};
+use advent_of_code_2015::profile::{find_profiles, get_profile_dir, DEFAULT_PROFILE};
+use advent_of_code_2015::report::{to_json, to_junit, ReportEntry, Status};
+use advent_of_code_2015::runner::{get_input_path, read_input, run_day, DayOutcome, RunOptions};
+use advent_of_code_2015::verify::{verify_profile, AnswerDb, PartVerification, Verdict};
+use advent_of_code_2015::*;
+
+const USAGE: &str = "\
+Usage: aoc2015 <DAY|all> [PART] [--input <FILE>] [--format <FORMAT>] [--output <FILE>]
+                       [--timeout <SECONDS>] [--stack-size <MIB>] [--verbose]
+       aoc2015 verify [--profile <NAME> | --all-profiles] [--answers <FILE>] [--input-dir <DIR>]
+                      [--format <FORMAT>] [--output <FILE>] [--timeout <SECONDS>] [--stack-size <MIB>]
+       aoc2015 bench <DAY|all> [--runs <N>] [--baseline <FILE>] [--save-baseline <FILE>]
+                     [--threshold <PERCENT>] [--profile <NAME>] [--input-dir <DIR>]
+                     [--timeout <SECONDS>] [--stack-size <MIB>]
+
+Runs the solutions for Advent of Code 2015 without needing cargo-aoc.
+
+Commands:
+  <DAY|all>             run the given day, or \"all\" to run every implemented day
+  verify                run every implemented day and check the answers against the known answers
+                        for a profile's puzzle inputs
+  bench                 time the generator and both parts of each day over several runs
+
+Arguments:
+  PART                  part to run (1 or 2); both parts are run if omitted
+
+Options:
+  -i, --input <FILE>    read puzzle input from FILE, or from stdin if FILE is \"-\"
+                        (default: input/2015/day<DAY>.txt)
+  -p, --profile <NAME>  profile whose inputs and known answers are checked (default: default)
+      --all-profiles    check every profile found in the input directory
+  -a, --answers <FILE>  file holding the known answers (default: input/2015/answers.txt)
+  -d, --input-dir <DIR> directory holding the puzzle inputs, with the inputs for each profile
+                        other than the default in a subdirectory (default: input/2015)
+  -f, --format <FORMAT> format of the results from running or verifying days: text, json or
+                        junit (default: text)
+  -o, --output <FILE>   write json or junit results to FILE instead of stdout
+  -n, --runs <N>        number of times to run each day when benchmarking (default: 10)
+      --baseline <FILE> compare benchmark timings against the baseline saved in FILE
+      --save-baseline <FILE>
+                        save the median benchmark timings to FILE as a new baseline
+      --threshold <PERCENT>
+                        slowdown against the baseline reported as a regression (default: 20)
+  -t, --timeout <SECONDS>
+                        give up on the generator or a part after SECONDS and report it as an
+                        error (default: no timeout)
+      --stack-size <MIB>
+                        stack size for the thread running the generator and each part, in MiB
+                        (default: 256)
+  -v, --verbose         also print details of how each answer was reached, such as the route taken,
+                        for days that give them (text format only)
+  -h, --help            print this help message";
+
+/// Default directory holding the puzzle inputs.
+const DEFAULT_INPUT_DIR: &str = "./input/2015";
+
+/// Default file holding the known answers.
+const DEFAULT_ANSWERS_FILE: &str = "./input/2015/answers.txt";
+
+/// Default number of times each day is run when benchmarking.
+const DEFAULT_BENCH_RUNS: usize = 10;
+
+/// Default slowdown, as a percentage of the baseline timing, reported as a regression.
+const DEFAULT_REGRESSION_THRESHOLD: f64 = 20.0;
+
+/// Format in which the results from running or verifying days are written.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Junit,
+}
+
+/// Destination and format for the results from running or verifying days.
+struct Output {
+    format: Format,
+    path: Option<PathBuf>,
+}
+
+/// Command given to the runner on the command line.
+enum Command {
+    Help,
+    Run {
+        days: Vec<u32>,
+        parts: Vec<u32>,
+        input: Option<String>,
+        output: Output,
+        options: RunOptions,
+    },
+    Verify {
+        profiles: Option<Vec<String>>,
+        answers: PathBuf,
+        input_dir: PathBuf,
+        output: Output,
+        options: RunOptions,
+    },
+    Bench {
+        days: Vec<u32>,
+        input_dir: PathBuf,
+        runs: usize,
+        baseline: Option<PathBuf>,
+        save_baseline: Option<PathBuf>,
+        threshold: f64,
+        options: RunOptions,
+    },
+}
+
+fn main() {
+    let command = match parse_args(env::args().skip(1).collect()) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+    let success = match command {
+        Command::Help => {
+            println!("{}", USAGE);
+            true
+        }
+        Command::Run {
+            days,
+            parts,
+            input,
+            output,
+            options,
+        } => run(&days, &parts, &input, &output, &options),
+        Command::Verify {
+            profiles,
+            answers,
+            input_dir,
+            output,
+            options,
+        } => verify(profiles, &answers, &input_dir, &output, &options),
+        Command::Bench {
+            days,
+            input_dir,
+            runs,
+            baseline,
+            save_baseline,
+            threshold,
+            options,
+        } => bench(&days, &input_dir, runs, &baseline, &save_baseline, threshold, &options),
+    };
+    if !success {
+        process::exit(1);
+    }
+}
+
+/// Parses the command-line arguments into the command to be carried out.
+fn parse_args(args: Vec<String>) -> Result<Command, String> {
+    let mut positional: Vec<String> = vec![];
+    let mut input: Option<String> = None;
+    let mut profiles = Some(vec![String::from(DEFAULT_PROFILE)]);
+    let mut answers = PathBuf::from(DEFAULT_ANSWERS_FILE);
+    let mut input_dir = PathBuf::from(DEFAULT_INPUT_DIR);
+    let mut runs = DEFAULT_BENCH_RUNS;
+    let mut baseline: Option<PathBuf> = None;
+    let mut save_baseline: Option<PathBuf> = None;
+    let mut threshold = DEFAULT_REGRESSION_THRESHOLD;
+    let mut output = Output {
+        format: Format::Text,
+        path: None,
+    };
+    let mut options = RunOptions::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--all-profiles" => profiles = None,
+            "-v" | "--verbose" => options.verbose = true,
+            "-i" | "--input" | "-p" | "--profile" | "-a" | "--answers" | "-d" | "--input-dir" | "-n"
+            | "--runs" | "--baseline" | "--save-baseline" | "--threshold" | "-f" | "--format" | "-o"
+            | "--output" | "-t" | "--timeout" | "--stack-size" => {
+                let value = match args.next() {
+                    Some(value) => value,
+                    None => return Err(format!("missing value for {}", arg)),
+                };
+                match arg.as_str() {
+                    "-i" | "--input" => input = Some(value),
+                    "-p" | "--profile" => profiles = Some(vec![value]),
+                    "-a" | "--answers" => answers = PathBuf::from(value),
+                    "-d" | "--input-dir" => input_dir = PathBuf::from(value),
+                    "-n" | "--runs" => match value.parse::<usize>() {
+                        Ok(value) if value > 0 => runs = value,
+                        _ => return Err(format!("invalid number of runs {}", value)),
+                    },
+                    "--baseline" => baseline = Some(PathBuf::from(value)),
+                    "--save-baseline" => save_baseline = Some(PathBuf::from(value)),
+                    "-f" | "--format" => match value.as_str() {
+                        "text" => output.format = Format::Text,
+                        "json" => output.format = Format::Json,
+                        "junit" => output.format = Format::Junit,
+                        _ => return Err(format!("unknown format {}", value)),
+                    },
+                    "-o" | "--output" => output.path = Some(PathBuf::from(value)),
+                    "-t" | "--timeout" => match value.parse::<f64>() {
+                        Ok(seconds) if seconds > 0.0 && seconds.is_finite() => {
+                            options.timeout = Some(Duration::from_secs_f64(seconds))
+                        }
+                        _ => return Err(format!("invalid timeout {}", value)),
+                    },
+                    "--stack-size" => match value.parse::<usize>() {
+                        Ok(mib) if mib > 0 && mib <= 1 << 16 => options.stack_size = mib * 1024 * 1024,
+                        _ => return Err(format!("invalid stack size {}", value)),
+                    },
+                    _ => match value.parse::<f64>() {
+                        Ok(value) if value >= 0.0 => threshold = value,
+                        _ => return Err(format!("invalid threshold {}", value)),
+                    },
+                }
+            }
+            _ if arg.starts_with('-') && arg != "-" => return Err(format!("unknown option {}", arg)),
+            _ => positional.push(arg),
+        }
+    }
+    if output.path.is_some() && output.format == Format::Text {
+        return Err(String::from("--output can only be used with the json or junit formats"));
+    }
+    let command = positional.first().map(|x| x.as_str());
+    if options.verbose && (command == Some("verify") || command == Some("bench")) {
+        return Err(String::from("--verbose can only be used when running days"));
+    }
+    if options.verbose && output.format != Format::Text {
+        return Err(String::from("--verbose can only be used with the text format"));
+    }
+    if positional.first().map(|x| x.as_str()) == Some("verify") {
+        if positional.len() > 1 {
+            return Err(format!("unexpected argument {}", positional[1]));
+        }
+        return Ok(Command::Verify {
+            profiles,
+            answers,
+            input_dir,
+            output,
+            options,
+        });
+    }
+    if positional.first().map(|x| x.as_str()) == Some("bench") {
+        if positional.len() > 2 {
+            return Err(format!("unexpected argument {}", positional[2]));
+        }
+        // Benchmarks are run against a single profile's inputs
+        let input_dir = match profiles {
+            Some(profiles) => get_profile_dir(&input_dir, &profiles[0]),
+            None => return Err(String::from("--all-profiles cannot be used when benchmarking")),
+        };
+        return Ok(Command::Bench {
+            days: parse_days(positional.get(1))?,
+            input_dir,
+            runs,
+            baseline,
+            save_baseline,
+            threshold,
+            options,
+        });
+    }
+    // Determine which days are to be run
+    let days = parse_days(positional.first())?;
+    // Determine which parts are to be run
+    let parts = match positional.get(1).map(|x| x.as_str()) {
+        Some("1") => vec![1],
+        Some("2") => vec![2],
+        Some(part) => return Err(format!("invalid part {} (expected 1 or 2)", part)),
+        None => vec![1, 2],
+    };
+    if positional.len() > 2 {
+        return Err(format!("unexpected argument {}", positional[2]));
+    }
+    if days.len() > 1 && input.is_some() {
+        return Err(String::from("--input can only be used when running a single day"));
+    }
+    return Ok(Command::Run {
+        days,
+        parts,
+        input,
+        output,
+        options,
+    });
+}
+
+/// Parses the argument giving the day to run, or "all" to run every implemented day.
+fn parse_days(arg: Option<&String>) -> Result<Vec<u32>, String> {
+    match arg.map(|x| x.as_str()) {
+        Some("all") => return Ok(get_days()),
+        Some(day) => match day.parse::<u32>() {
+            Ok(day) if get_solver(day).is_some() => return Ok(vec![day]),
+            _ => return Err(format!("no solution implemented for day {}", day)),
+        },
+        None => return Err(String::from("missing DAY argument")),
+    }
+}
+
+/// Reads the raw input for the day, either from the given file, stdin or the default location.
+fn read_day_input(day: u32, input: &Option<String>) -> Result<String, AocError> {
+    match input.as_deref() {
+        Some("-") => {
+            let mut raw_input = String::new();
+            io::stdin().read_to_string(&mut raw_input)?;
+            return Ok(raw_input);
+        }
+        Some(path) => return read_input(Path::new(path)),
+        None => return read_input(&get_input_path(Path::new(DEFAULT_INPUT_DIR), day)),
+    }
+}
+
+/// Runs the requested parts of each day and writes the answers and timings in the requested
+/// format. Returns false if any part could not be solved.
+fn run(days: &[u32], parts: &[u32], input: &Option<String>, output: &Output, options: &RunOptions) -> bool {
+    let mut entries: Vec<ReportEntry> = vec![];
+    for day in days {
+        let outcome = match read_day_input(*day, input) {
+            Ok(raw_input) => run_day(*day, parts, &raw_input, options),
+            Err(err) => DayOutcome {
+                day: *day,
+                parse_time: Duration::ZERO,
+                parts: Err(err),
+            },
+        };
+        if output.format == Format::Text {
+            print_outcome(&outcome);
+        }
+        entries.append(&mut ReportEntry::from_outcome(&outcome, parts));
+    }
+    let success = entries.iter().all(|x| x.status != Status::Error);
+    if output.format != Format::Text {
+        return write_report(&entries, output) && success;
+    }
+    return success;
+}
+
+/// Prints the answers and timings from running a day, or the errors that occurred.
+fn print_outcome(outcome: &DayOutcome) {
+    let day = outcome.day;
+    let part_outcomes = match &outcome.parts {
+        Ok(part_outcomes) => part_outcomes,
+        Err(err) => {
+            eprintln!("Day {} - error: {}", day, err);
+            return;
+        }
+    };
+    println!("Day {} - parse: {:?}", day, outcome.parse_time);
+    for part_outcome in part_outcomes {
+        match &part_outcome.answer {
+            Ok(answer) => {
+                println!(
+                    "Day {} - Part {}: {} (solve: {:?})",
+                    day, part_outcome.part, answer, part_outcome.solve_time
+                );
+                if let Some(explanation) = &part_outcome.explanation {
+                    for line in explanation.lines() {
+                        println!("    {}", line);
+                    }
+                }
+            }
+            Err(AocError::NotImplemented(_, _)) => println!("Day {} - Part {}: not implemented yet", day, part_outcome.part),
+            Err(err) => eprintln!("Day {} - Part {}: error: {}", day, part_outcome.part, err),
+        }
+    }
+}
+
+/// Writes the entries as a report in the requested format, either to the output file or stdout.
+/// Returns false if the report could not be written.
+fn write_report(entries: &[ReportEntry], output: &Output) -> bool {
+    let report = match output.format {
+        Format::Json => to_json(entries),
+        _ => to_junit(entries),
+    };
+    match &output.path {
+        Some(path) => {
+            if let Err(err) = fs::write(path, report) {
+                eprintln!("error: failed to write results to {}: {}", path.display(), err);
+                return false;
+            }
+        }
+        None => print!("{}", report),
+    }
+    return true;
+}
+
+/// Verifies every day against the known answers for each profile and writes the results in the
+/// requested format. All profiles found in the input directory are verified if no profiles are
+/// given. Returns false if any answer was wrong or could not be found.
+fn verify(
+    profiles: Option<Vec<String>>,
+    answers: &Path,
+    input_dir: &Path,
+    output: &Output,
+    options: &RunOptions,
+) -> bool {
+    let answer_db = match AnswerDb::load(answers) {
+        Ok(answer_db) => answer_db,
+        Err(err) => {
+            eprintln!("error: failed to load answers from {}: {}", answers.display(), err);
+            return false;
+        }
+    };
+    let profiles = match profiles {
+        Some(profiles) => profiles,
+        None => match find_profiles(input_dir) {
+            Ok(profiles) => profiles,
+            Err(err) => {
+                eprintln!("error: failed to find profiles in {}: {}", input_dir.display(), err);
+                return false;
+            }
+        },
+    };
+    let mut verifications: Vec<PartVerification> = vec![];
+    for profile in profiles.iter() {
+        let mut profile_verifications = verify_profile(&answer_db, profile, input_dir, options);
+        if output.format == Format::Text {
+            println!("Verifying answers for profile \"{}\"", profile);
+            print_verifications(&profile_verifications);
+        }
+        verifications.append(&mut profile_verifications);
+    }
+    if output.format == Format::Text && profiles.len() > 1 {
+        print!("Total over {} profiles: ", profiles.len());
+        print_summary(&verifications);
+    }
+    let success = verifications
+        .iter()
+        .all(|x| x.verdict != Verdict::Fail && x.verdict != Verdict::Error);
+    if output.format != Format::Text {
+        let entries = verifications
+            .iter()
+            .map(ReportEntry::from_verification)
+            .collect::<Vec<ReportEntry>>();
+        return write_report(&entries, output) && success;
+    }
+    return success;
+}
+
+/// Prints a table giving the outcome of each verification, followed by a summary line.
+fn print_verifications(verifications: &[PartVerification]) {
+    println!(
+        "{:>3}  {:>4}  {:<7}  {:>16}  {:>16}  {:>12}  {:>12}",
+        "Day", "Part", "Status", "Answer", "Expected", "Parse", "Solve"
+    );
+    for verification in verifications.iter() {
+        let answer = match &verification.answer {
+            Some(answer) => answer.to_string(),
+            None => String::from("-"),
+        };
+        println!(
+            "{:>3}  {:>4}  {:<7}  {:>16}  {:>16}  {:>12}  {:>12}",
+            verification.day,
+            verification.part,
+            verification.verdict,
+            answer,
+            verification.expected.as_deref().unwrap_or("-"),
+            format!("{:?}", verification.parse_time),
+            format!("{:?}", verification.solve_time),
+        );
+    }
+    // Errors are listed after the table so they do not break up its columns
+    for verification in verifications.iter() {
+        if let Some(error) = &verification.error {
+            println!("Day {} - Part {}: {}", verification.day, verification.part, error);
+        }
+    }
+    print_summary(verifications);
+}
+
+/// Prints the number of verifications with each verdict.
+fn print_summary(verifications: &[PartVerification]) {
+    let count = |verdict: Verdict| verifications.iter().filter(|x| x.verdict == verdict).count();
+    println!(
+        "{} passed, {} failed, {} missing, {} errors",
+        count(Verdict::Pass),
+        count(Verdict::Fail),
+        count(Verdict::Missing),
+        count(Verdict::Error)
+    );
+}
+
+/// Benchmarks each day and prints the timings, comparing them against the baseline if one is
+/// given. Returns false if any day could not be run or has regressed against the baseline.
+fn bench(
+    days: &[u32],
+    input_dir: &Path,
+    runs: usize,
+    baseline: &Option<PathBuf>,
+    save_baseline: &Option<PathBuf>,
+    threshold: f64,
+    options: &RunOptions,
+) -> bool {
+    let baseline = match baseline.as_deref().map(Baseline::load) {
+        Some(Ok(baseline)) => Some(baseline),
+        Some(Err(err)) => {
+            eprintln!("error: failed to load baseline: {}", err);
+            return false;
+        }
+        None => None,
+    };
+    println!("Benchmarking over {} runs using inputs from {}", runs, input_dir.display());
+    println!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}  {:>12}  {:>8}",
+        "Day", "Stage", "Min", "Median", "Max", "Baseline", "Change"
+    );
+    let mut success = true;
+    let mut benchmarks: Vec<StageBenchmark> = vec![];
+    for day in days {
+        let day_benchmarks = match read_input(&get_input_path(input_dir, *day)) {
+            Ok(raw_input) => bench_day(*day, &raw_input, runs, options),
+            Err(err) => Err(err),
+        };
+        let mut day_benchmarks = match day_benchmarks {
+            Ok(day_benchmarks) => day_benchmarks,
+            Err(err) => {
+                println!("{:>3}  error: {}", day, err);
+                success = false;
+                continue;
+            }
+        };
+        for benchmark in day_benchmarks.iter() {
+            let stats = &benchmark.stats;
+            let (baseline_median, change) = match baseline.as_ref().and_then(|x| x.get_median(*day, benchmark.stage)) {
+                Some(baseline_median) => {
+                    let change = (stats.median.as_secs_f64() / baseline_median.as_secs_f64() - 1.0) * 100.0;
+                    let mut change = format!("{:+.1}%", change);
+                    if is_regression(stats.median, baseline_median, threshold) {
+                        change.push_str(" REGRESSION");
+                        success = false;
+                    }
+                    (format!("{:?}", baseline_median), change)
+                }
+                None => (String::from("-"), String::from("-")),
+            };
+            println!(
+                "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}  {:>12}  {:>8}",
+                day,
+                benchmark.stage,
+                format!("{:?}", stats.min),
+                format!("{:?}", stats.median),
+                format!("{:?}", stats.max),
+                baseline_median,
+                change
+            );
+        }
+        benchmarks.append(&mut day_benchmarks);
+    }
+    if let Some(path) = save_baseline {
+        match Baseline::from_benchmarks(&benchmarks).save(path) {
+            Ok(()) => println!("Saved baseline to {}", path.display()),
+            Err(err) => {
+                eprintln!("error: failed to save baseline to {}: {}", path.display(), err);
+                success = false;
+            }
+        }
+    }
+    return success;
+}
@@ -1,10 +1,22 @@
+use super::error::ParseError;
+use super::solution::{Answer, Solution};
+use super::utils::input::PuzzleInput;
+
 #[aoc_generator(day1)]
-fn generate_input(raw_input: &str) -> String {
-    return String::from(raw_input);
+pub fn generate_input(raw_input: &str) -> Result<String, ParseError> {
+    let input = PuzzleInput::new(raw_input);
+    let instructions = input.get_line();
+    // Check that the input only contains opening and closing parentheses
+    for (i, c) in instructions.chars().enumerate() {
+        if c != '(' && c != ')' {
+            return Err(ParseError::new(1, 1, i + 1, &c.to_string(), "'(' or ')'"));
+        }
+    }
+    return Ok(String::from(instructions));
 }
 
 #[aoc(day1, part1)]
-fn solve_part_1(input: &String) -> i64 {
+pub fn solve_part_1(input: &String) -> i64 {
     let mut floor: i64 = 0;
     for c in input.chars() {
         match c {
@@ -17,7 +29,7 @@ fn solve_part_1(input: &String) -> i64 {
 }
 
 #[aoc(day1, part2)]
-fn solve_part_2(input: &String) -> usize {
+pub fn solve_part_2(input: &String) -> Option<usize> {
     let mut pos: usize = 0;
     let mut floor: i64 = 0;
     for c in input.chars() {
@@ -29,10 +41,34 @@ fn solve_part_2(input: &String) -> usize {
         // Check if current character resulted in basement floor being reached
         pos += 1;
         if floor == -1 {
-            return pos;
+            return Some(pos);
         }
     }
-    panic!("D1_P2: did not reach basement by end of input!");
+    // Basement was never reached
+    return None;
+}
+
+/// Answer given when the instructions never reach the basement.
+const NO_BASEMENT: &str = "never reaches the basement";
+
+/// Solution for day 1, for use through the crate's solution registry.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Input = String;
+
+    fn parse(raw_input: &str) -> Result<String, ParseError> {
+        return generate_input(raw_input);
+    }
+
+    fn part1(input: &String) -> Answer {
+        return solve_part_1(input).into();
+    }
+
+    fn part2(input: &String) -> Answer {
+        return solve_part_2(input).map_or(Answer::from(NO_BASEMENT), Answer::from);
+    }
 }
 
 #[cfg(test)]
@@ -42,15 +78,57 @@ mod tests {
 
     #[test]
     fn test_d01_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day1.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day1.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!(232, result);
     }
 
     #[test]
     fn test_d01_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day1.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day1.txt").unwrap()).unwrap();
         let result = solve_part_2(&input);
-        assert_eq!(1783, result);
+        assert_eq!(Some(1783), result);
+    }
+
+    #[test]
+    fn test_d01_input_invalid() {
+        let err = generate_input("(()x)").unwrap_err();
+        assert_eq!((1, 4), (err.get_line(), err.get_column()));
+        assert_eq!("x", err.get_text());
+    }
+
+    #[test]
+    fn test_d01_input_normalised() {
+        // Byte order marks and trailing line endings are not treated as instructions
+        assert_eq!("(()", generate_input("\u{feff}(()\r\n").unwrap());
+    }
+
+    #[test]
+    fn test_d01_p1_examples() {
+        let examples = [
+            ("(())", 0),
+            ("()()", 0),
+            ("(((", 3),
+            ("(()(()(", 3),
+            ("))(((((", 3),
+            ("())", -1),
+            ("))(", -1),
+            (")))", -3),
+            (")())())", -3),
+        ];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_1(&input), "input: {}", raw_input);
+        }
+    }
+
+    #[test]
+    fn test_d01_p2_examples() {
+        let examples = [(")", Some(1)), ("()())", Some(5)), ("(((", None)];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_2(&input), "input: {}", raw_input);
+        }
+        assert_eq!(Answer::from(NO_BASEMENT), Day01::part2(&String::from("(((")));
     }
 }
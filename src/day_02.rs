@@ -1,7 +1,11 @@
 use regex::Regex;
+use super::error::ParseError;
+use super::solution::{Answer, Solution};
+use super::utils::input::PuzzleInput;
 
 // Represents a three-dimensional object defined by its length, width and height (all in feet).
-struct Present {
+#[derive(Debug)]
+pub struct Present {
     length: u64,
     width: u64,
     height: u64
@@ -49,28 +53,43 @@ impl Present {
 }
 
 #[aoc_generator(day2)]
-fn generate_input(raw_input: &str) -> Vec<Present> {
+pub fn generate_input(raw_input: &str) -> Result<Vec<Present>, ParseError> {
     let present_regex = Regex::new(r"^(\d+)x(\d+)x(\d+)$").unwrap();
     let mut presents_result = vec![];
-    for line in raw_input.lines() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
-        if present_regex.is_match(line) {
-            let captures = present_regex.captures(line).unwrap();
-            let length = captures[1].parse::<u64>().unwrap();
-            let width = captures[2].parse::<u64>().unwrap();
-            let height = captures[3].parse::<u64>().unwrap();
-            let present = Present::new(length, width, height);
-            presents_result.push(present);
+    let input = PuzzleInput::new(raw_input);
+    for (line_num, line) in input.get_lines() {
+        let captures = match present_regex.captures(line) {
+            Some(captures) => captures,
+            None => {
+                let column = input.get_column(line_num, 0);
+                return Err(ParseError::new(2, line_num, column, line, "dimensions in the form LxWxH"));
+            }
+        };
+        // Dimensions are known to be digits, but may still be too large to fit
+        let mut dimensions: Vec<u64> = vec![];
+        for i in 1..=3 {
+            let field = captures.get(i).unwrap();
+            match field.as_str().parse::<u64>() {
+                Ok(value) => dimensions.push(value),
+                Err(_) => {
+                    return Err(ParseError::new(
+                        2,
+                        line_num,
+                        input.get_column(line_num, field.start()),
+                        field.as_str(),
+                        "a dimension that fits in a u64",
+                    ))
+                }
+            }
         }
+        let present = Present::new(dimensions[0], dimensions[1], dimensions[2]);
+        presents_result.push(present);
     }
-    return presents_result;
+    return Ok(presents_result);
 }
 
 #[aoc(day2, part1)]
-fn solve_part_1(input: &Vec<Present>) -> u64 {
+pub fn solve_part_1(input: &Vec<Present>) -> u64 {
     let mut total_area = 0;
     for present in input {
         total_area += present.calculate_surface_area();
@@ -79,7 +98,7 @@ fn solve_part_1(input: &Vec<Present>) -> u64 {
 }
 
 #[aoc(day2, part2)]
-fn solve_part_2(input: &Vec<Present>) -> u64 {
+pub fn solve_part_2(input: &Vec<Present>) -> u64 {
     let mut total_ribbon_length = 0;
     for present in input {
         total_ribbon_length += present.calculate_ribbon_length();
@@ -87,6 +106,26 @@ fn solve_part_2(input: &Vec<Present>) -> u64 {
     return total_ribbon_length;
 }
 
+/// Solution for day 2, for use through the crate's solution registry.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Input = Vec<Present>;
+
+    fn parse(raw_input: &str) -> Result<Vec<Present>, ParseError> {
+        return generate_input(raw_input);
+    }
+
+    fn part1(input: &Vec<Present>) -> Answer {
+        return solve_part_1(input).into();
+    }
+
+    fn part2(input: &Vec<Present>) -> Answer {
+        return solve_part_2(input).into();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,15 +133,43 @@ mod tests {
 
     #[test]
     fn test_d02_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day2.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day2.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!(1588178, result);
     }
 
     #[test]
     fn test_d02_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day2.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day2.txt").unwrap()).unwrap();
         let result = solve_part_2(&input);
         assert_eq!(3783758, result);
     }
+
+    #[test]
+    fn test_d02_input_invalid() {
+        let err = generate_input("2x3x4\n1x1\n").unwrap_err();
+        assert_eq!((2, 1), (err.get_line(), err.get_column()));
+        assert_eq!("1x1", err.get_text());
+        // Columns count from the start of the line as given, including any indentation
+        let err = generate_input("2x3x4\n  1x1x99999999999999999999\n").unwrap_err();
+        assert_eq!((2, 7), (err.get_line(), err.get_column()));
+    }
+
+    #[test]
+    fn test_d02_p1_examples() {
+        let examples = [("2x3x4", 58), ("1x1x10", 43)];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_1(&input), "input: {}", raw_input);
+        }
+    }
+
+    #[test]
+    fn test_d02_p2_examples() {
+        let examples = [("2x3x4", 34), ("1x1x10", 14)];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_2(&input), "input: {}", raw_input);
+        }
+    }
 }
@@ -1,57 +1,89 @@
-use std::collections::HashSet;
-use super::utils::carto::{CardinalDirection, Point2D};
+use super::error::ParseError;
+use super::solution::{Answer, Solution};
+use super::utils::carto::{CardinalDirection, Point2D, Walker};
+use super::utils::input::PuzzleInput;
+use super::utils::sparsegrid::SparseGrid;
 
 #[aoc_generator(day3)]
-fn generate_input(raw_input: &str) -> Vec<CardinalDirection> {
+pub fn generate_input(raw_input: &str) -> Result<Vec<CardinalDirection>, ParseError> {
     let mut directions: Vec<CardinalDirection> = vec![];
-    for c in raw_input.chars() {
-        match c {
-            '^' => directions.push(CardinalDirection::North),
-            '<' => directions.push(CardinalDirection::West),
-            '>' => directions.push(CardinalDirection::East),
-            'v' => directions.push(CardinalDirection::South),
-            _ => ()
+    let input = PuzzleInput::new(raw_input);
+    for (line_num, line) in input.get_lines() {
+        for (col_num, c) in line.chars().enumerate() {
+            match c {
+                '^' => directions.push(CardinalDirection::North),
+                '<' => directions.push(CardinalDirection::West),
+                '>' => directions.push(CardinalDirection::East),
+                'v' => directions.push(CardinalDirection::South),
+                _ if c.is_whitespace() => (),
+                _ => {
+                    return Err(ParseError::new(
+                        3,
+                        line_num,
+                        input.get_column(line_num, col_num),
+                        &c.to_string(),
+                        "one of '^', '<', '>' or 'v'",
+                    ))
+                }
+            }
         }
     }
-    return directions;
+    return Ok(directions);
 }
 
 #[aoc(day3, part1)]
-fn solve_part_1(input: &Vec<CardinalDirection>) -> usize {
-    let mut visited: HashSet<Point2D> = HashSet::new();
-    let mut current_pos = Point2D::new(0, 0);
-    visited.insert(current_pos);
+pub fn solve_part_1(input: &Vec<CardinalDirection>) -> usize {
+    let mut santa = Walker::new(Point2D::new(0, 0), CardinalDirection::North);
     for dir in input {
-        let unit_vec = dir.get_unit_vector();
-        current_pos.move_point(unit_vec.0, unit_vec.1);
-        visited.insert(current_pos);
+        santa.strafe(*dir, 1);
     }
-    return visited.len();
+    return count_presents(&[santa]).len();
 }
 
 #[aoc(day3, part2)]
-fn solve_part_2(input: &Vec<CardinalDirection>) -> usize {
-    let mut visited: HashSet<Point2D> = HashSet::new();
-    let mut santa_pos = Point2D::new(0, 0);
-    let mut robo_santa_pos = Point2D::new(0, 0);
-    visited.insert(Point2D::new(0, 0));
-    let mut i = 0;
-    loop {
-        if i >= input.len() {
-            break;
+pub fn solve_part_2(input: &Vec<CardinalDirection>) -> usize {
+    // Santa and Robo-Santa both start at the origin
+    let mut walkers = [
+        Walker::new(Point2D::new(0, 0), CardinalDirection::North),
+        Walker::new(Point2D::new(0, 0), CardinalDirection::North),
+    ];
+    for (i, dir) in input.iter().enumerate() {
+        // Santa and Robo-Santa take turns following the directions
+        walkers[i % 2].strafe(*dir, 1);
+    }
+    return count_presents(&walkers).len();
+}
+
+/// Counts the presents delivered to each house along the paths of the walkers, including the
+/// present delivered to the starting house by each walker.
+fn count_presents(walkers: &[Walker]) -> SparseGrid<u64> {
+    let mut houses = SparseGrid::new_counter();
+    for walker in walkers {
+        for point in walker.get_path() {
+            houses.increment(point);
         }
-        // Move santa
-        let santa_unit_vec = input[i].get_unit_vector();
-        santa_pos.move_point(santa_unit_vec.0, santa_unit_vec.1);
-        visited.insert(santa_pos);
-        // Move robo santa
-        let robo_santa_unit_vec = input[i + 1].get_unit_vector();
-        robo_santa_pos.move_point(robo_santa_unit_vec.0, robo_santa_unit_vec.1);
-        visited.insert(robo_santa_pos);
-        // Advance to next pair of directions
-        i += 2;
     }
-    return visited.len();
+    return houses;
+}
+
+/// Solution for day 3, for use through the crate's solution registry.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Input = Vec<CardinalDirection>;
+
+    fn parse(raw_input: &str) -> Result<Vec<CardinalDirection>, ParseError> {
+        return generate_input(raw_input);
+    }
+
+    fn part1(input: &Vec<CardinalDirection>) -> Answer {
+        return solve_part_1(input).into();
+    }
+
+    fn part2(input: &Vec<CardinalDirection>) -> Answer {
+        return solve_part_2(input).into();
+    }
 }
 
 #[cfg(test)]
@@ -61,15 +93,55 @@ mod tests {
 
     #[test]
     fn test_d03_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day3.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day3.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!(2572, result);
     }
 
     #[test]
     fn test_d03_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day3.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day3.txt").unwrap()).unwrap();
         let result = solve_part_2(&input);
         assert_eq!(2631, result);
     }
+
+    #[test]
+    fn test_d03_input_invalid() {
+        let err = generate_input("^>v<\n^^x").unwrap_err();
+        assert_eq!((2, 3), (err.get_line(), err.get_column()));
+        assert_eq!("x", err.get_text());
+        // Only arrows are accepted, not the direction letters used elsewhere
+        let err = generate_input("^>N<").unwrap_err();
+        assert_eq!((1, 3), (err.get_line(), err.get_column()));
+    }
+
+    #[test]
+    fn test_d03_p1_examples() {
+        let examples = [(">", 2), ("^>v<", 4), ("^v^v^v^v^v", 2)];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_1(&input), "input: {}", raw_input);
+        }
+    }
+
+    #[test]
+    fn test_d03_p2_examples() {
+        // An odd number of directions leaves Robo-Santa with one fewer move than Santa
+        let examples = [("^v", 3), ("^>v<", 3), ("^v^v^v^v^v", 11), ("^>v", 3)];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_2(&input), "input: {}", raw_input);
+        }
+    }
+
+    #[test]
+    fn test_d03_visit_counts() {
+        let mut santa = Walker::new(Point2D::new(0, 0), CardinalDirection::North);
+        for dir in generate_input("^v^v").unwrap() {
+            santa.strafe(dir, 1);
+        }
+        let houses = count_presents(&[santa]);
+        assert_eq!((3, 2), (*houses.get(&Point2D::new(0, 0)), *houses.get(&Point2D::new(0, -1))));
+        assert_eq!("*\n*", houses.render(|_| '*'));
+    }
 }
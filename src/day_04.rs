@@ -1,12 +1,20 @@
 use md5;
+use super::error::ParseError;
+use super::solution::{Answer, Solution};
+use super::utils::input::PuzzleInput;
 
 #[aoc_generator(day4)]
-fn generate_input(raw_input: &str) -> String {
-    return String::from(raw_input.trim());
+pub fn generate_input(raw_input: &str) -> Result<String, ParseError> {
+    let input = PuzzleInput::new(raw_input);
+    let secret_key = input.get_line();
+    if secret_key.is_empty() || secret_key.contains(char::is_whitespace) {
+        return Err(ParseError::new(4, 1, 1, secret_key, "a single non-empty secret key"));
+    }
+    return Ok(String::from(secret_key));
 }
 
 #[aoc(day4, part1)]
-fn solve_part_1(secret_key: &String) -> u64 {
+pub fn solve_part_1(secret_key: &String) -> u64 {
     let mut seq_id = 1;
     loop {
         // Check if current sequence ID mines AdventCoins
@@ -24,7 +32,7 @@ fn solve_part_1(secret_key: &String) -> u64 {
 }
 
 #[aoc(day4, part2)]
-fn solve_part_2(secret_key: &String) -> u64 {
+pub fn solve_part_2(secret_key: &String) -> u64 {
     let mut seq_id = 1;
     loop {
         // Check if current sequence ID mines AdventCoins
@@ -41,6 +49,26 @@ fn solve_part_2(secret_key: &String) -> u64 {
     return seq_id;
 }
 
+/// Solution for day 4, for use through the crate's solution registry.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    type Input = String;
+
+    fn parse(raw_input: &str) -> Result<String, ParseError> {
+        return generate_input(raw_input);
+    }
+
+    fn part1(input: &String) -> Answer {
+        return solve_part_1(input).into();
+    }
+
+    fn part2(input: &String) -> Answer {
+        return solve_part_2(input).into();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,15 +76,30 @@ mod tests {
 
     #[test]
     fn test_d04_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day4.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day4.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!(254575, result);
     }
 
     #[test]
     fn test_d04_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day4.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day4.txt").unwrap()).unwrap();
         let result = solve_part_2(&input);
         assert_eq!(1038736, result);
     }
+
+    #[test]
+    fn test_d04_input_invalid() {
+        assert!(generate_input("  \n").is_err());
+        assert!(generate_input("abc def").is_err());
+    }
+
+    #[test]
+    fn test_d04_p1_examples() {
+        let examples = [("abcdef", 609043), ("pqrstuv", 1048970)];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_1(&input), "input: {}", raw_input);
+        }
+    }
 }
@@ -1,20 +1,25 @@
 use fancy_regex::Regex;
+use super::error::ParseError;
+use super::solution::{Answer, Solution};
+use super::utils::input::PuzzleInput;
 
 #[aoc_generator(day5)]
-fn generate_input(raw_input: &str) -> Vec<String> {
+pub fn generate_input(raw_input: &str) -> Result<Vec<String>, ParseError> {
     let mut input_strings: Vec<String> = vec![];
-    for line in raw_input.lines() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
+    let input = PuzzleInput::new(raw_input);
+    for (line_num, line) in input.get_lines() {
+        // Candidate strings only contain lowercase letters
+        if let Some(col_num) = line.find(|c: char| !c.is_ascii_lowercase()) {
+            let column = input.get_column(line_num, col_num);
+            return Err(ParseError::new(5, line_num, column, line, "only lowercase letters"));
         }
         input_strings.push(String::from(line));
     }
-    return input_strings;
+    return Ok(input_strings);
 }
 
 #[aoc(day5, part1)]
-fn solve_part_1(input_strings: &Vec<String>) -> u64 {
+pub fn solve_part_1(input_strings: &Vec<String>) -> u64 {
     let mut nice_count = 0;
     // Create regexes to match against nice string properties
     let nice_1_regex = Regex::new(r"^.*[aeiou].*[aeiou].*[aeiou].*$").unwrap();
@@ -35,7 +40,7 @@ fn solve_part_1(input_strings: &Vec<String>) -> u64 {
 }
 
 #[aoc(day5, part2)]
-fn solve_part_2(input_strings: &Vec<String>) -> u64 {
+pub fn solve_part_2(input_strings: &Vec<String>) -> u64 {
     let mut nice_count = 0;
     // Define nice string properties with regexes
     let nice_1_regex = Regex::new(r"^.*([[:alpha:]])([[:alpha:]]).*\1\2.*$").unwrap();
@@ -51,6 +56,26 @@ fn solve_part_2(input_strings: &Vec<String>) -> u64 {
     return nice_count;
 }
 
+/// Solution for day 5, for use through the crate's solution registry.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Input = Vec<String>;
+
+    fn parse(raw_input: &str) -> Result<Vec<String>, ParseError> {
+        return generate_input(raw_input);
+    }
+
+    fn part1(input: &Vec<String>) -> Answer {
+        return solve_part_1(input).into();
+    }
+
+    fn part2(input: &Vec<String>) -> Answer {
+        return solve_part_2(input).into();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,15 +83,54 @@ mod tests {
 
     #[test]
     fn test_d05_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day5.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day5.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!(255, result);
     }
 
     #[test]
     fn test_d05_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day5.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day5.txt").unwrap()).unwrap();
         let result = solve_part_2(&input);
         assert_eq!(55, result);
     }
+
+    #[test]
+    fn test_d05_input_invalid() {
+        let err = generate_input("ugknbfddgicrmopn\naaA\n").unwrap_err();
+        assert_eq!((2, 3), (err.get_line(), err.get_column()));
+    }
+
+    #[test]
+    fn test_d05_p1_examples() {
+        let examples = [
+            ("ugknbfddgicrmopn", 1),
+            ("aaa", 1),
+            ("jchzalrnumimnmhp", 0),
+            ("haegwjzuvuyypxyu", 0),
+            ("dvszwmarrgswjxmb", 0),
+        ];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_1(&input), "input: {}", raw_input);
+        }
+        let input = generate_input(&read_to_string("./input/2015/test/t_day_05_01.txt").unwrap()).unwrap();
+        assert_eq!(2, solve_part_1(&input));
+    }
+
+    #[test]
+    fn test_d05_p2_examples() {
+        let examples = [
+            ("qjhvhtzxzqqjkmpb", 1),
+            ("xxyxx", 1),
+            ("uurcxstgmygtbstg", 0),
+            ("ieodomkazucvgmuy", 0),
+        ];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_2(&input), "input: {}", raw_input);
+        }
+        let input = generate_input(&read_to_string("./input/2015/test/t_day_05_02.txt").unwrap()).unwrap();
+        assert_eq!(2, solve_part_2(&input));
+    }
 }
@@ -1,6 +1,12 @@
 use regex::Regex;
+use super::error::ParseError;
+use super::solution::{Answer, Solution};
+use super::utils::carto::{Point2D, Rect};
+use super::utils::grid::Grid;
+use super::utils::input::PuzzleInput;
 
-enum LightInstruction {
+#[derive(Debug)]
+pub enum LightInstruction {
     TurnOn,
     TurnOff,
     Toggle,
@@ -18,112 +24,145 @@ impl LightInstruction {
     }
 }
 
-struct LightChangeOperation {
+#[derive(Debug)]
+pub struct LightChangeOperation {
     i_type: LightInstruction,
-    top_left: (usize, usize),
-    bot_right: (usize, usize),
+    region: Rect,
 }
 
 impl LightChangeOperation {
-    pub fn new(i_type: LightInstruction, top_left: (usize, usize), bot_right: (usize, usize)) -> Self {
+    pub fn new(i_type: LightInstruction, region: Rect) -> Self {
         Self {
             i_type: i_type,
-            top_left: top_left,
-            bot_right: bot_right,
+            region: region,
         }
     }
 }
 
 #[aoc_generator(day6)]
-fn generate_input(raw_input: &str) -> Vec<LightChangeOperation> {
+pub fn generate_input(raw_input: &str) -> Result<Vec<LightChangeOperation>, ParseError> {
     let mut operations: Vec<LightChangeOperation> = vec![];
     let operation_regex =
         Regex::new(r"^(turn on|turn off|toggle) (\d+),(\d+) through (\d+),(\d+)$").unwrap();
-    for line in raw_input.lines() {
-        // Trim leading and trailing whitespace, then ignore empty lines
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
+    let input = PuzzleInput::new(raw_input);
+    for (line_num, line) in input.get_lines() {
         // Extract light operation fields from current line
-        if operation_regex.is_match(line) {
-            let captures = operation_regex.captures(line).unwrap();
-            let i_type = LightInstruction::from_string(&captures[1]).unwrap();
-            let top_left_x = captures[2].parse::<usize>().unwrap();
-            let top_left_y = captures[3].parse::<usize>().unwrap();
-            let bot_right_x = captures[4].parse::<usize>().unwrap();
-            let bot_right_y = captures[5].parse::<usize>().unwrap();
-            let light_op = LightChangeOperation::new(
-                i_type,
-                (top_left_x, top_left_y),
-                (bot_right_x, bot_right_y),
-            );
-            operations.push(light_op);
+        let captures = match operation_regex.captures(line) {
+            Some(captures) => captures,
+            None => {
+                return Err(ParseError::new(
+                    6,
+                    line_num,
+                    input.get_column(line_num, 0),
+                    line,
+                    "\"<turn on|turn off|toggle> x,y through x,y\"",
+                ))
+            }
+        };
+        let i_type = LightInstruction::from_string(&captures[1]).unwrap();
+        // Coordinates must fall within the 1000x1000 light grid
+        let mut coords: Vec<i64> = vec![];
+        for i in 2..=5 {
+            let field = captures.get(i).unwrap();
+            match field.as_str().parse::<i64>() {
+                Ok(value) if value < 1000 => coords.push(value),
+                _ => {
+                    return Err(ParseError::new(
+                        6,
+                        line_num,
+                        input.get_column(line_num, field.start()),
+                        field.as_str(),
+                        "a coordinate in the range 0-999",
+                    ))
+                }
+            }
         }
+        // Corners must be given as top-left then bottom-right
+        if coords[0] > coords[2] || coords[1] > coords[3] {
+            let corner = captures.get(4).unwrap();
+            return Err(ParseError::new(
+                6,
+                line_num,
+                input.get_column(line_num, corner.start()),
+                &line[corner.start()..],
+                "bottom-right corner at or after the top-left corner",
+            ));
+        }
+        let region = Rect::new(Point2D::new(coords[0], coords[1]), Point2D::new(coords[2], coords[3]));
+        let light_op = LightChangeOperation::new(i_type, region);
+        operations.push(light_op);
     }
-    return operations;
+    return Ok(operations);
 }
 
 #[aoc(day6, part1)]
-fn solve_part_1(operations: &Vec<LightChangeOperation>) -> u64 {
-    // 1000x1000 matrix starts with all lights off
-    let mut light_matrix: [[bool; 1000];  1000] = [[false; 1000]; 1000];
+pub fn solve_part_1(operations: &Vec<LightChangeOperation>) -> u64 {
+    // 1000x1000 grid starts with all lights off
+    let mut light_grid: Grid<bool> = Grid::new(1000, 1000, false);
     // Process each operation
     for op in operations {
         // Iterate over each light covered by current operation and adjust state ASREQ
-        for y in op.top_left.1..=op.bot_right.1 {
-            for x in op.top_left.0..=op.bot_right.0 {
-                match op.i_type {
-                    LightInstruction::TurnOn => light_matrix[y][x] = true,
-                    LightInstruction::TurnOff => light_matrix[y][x] = false,
-                    LightInstruction::Toggle => light_matrix[y][x] = !light_matrix[y][x],
-                }
+        for (_, light) in light_grid.iter_region_mut(&op.region) {
+            match op.i_type {
+                LightInstruction::TurnOn => *light = true,
+                LightInstruction::TurnOff => *light = false,
+                LightInstruction::Toggle => *light = !*light,
             }
         }
     }
     // Count number of lights that are on after all operations have been processed
-    let mut on_count = 0;
-    for y in 0..1000 {
-        for x in 0..1000 {
-            if light_matrix[y][x] == true {
-                on_count += 1;
-            }
-        }
-    }
-    return on_count;
+    return light_grid.iter_values().filter(|light| **light).count() as u64;
 }
 
 #[aoc(day6, part2)]
-fn solve_part_2(operations: &Vec<LightChangeOperation>) -> i64 {
+pub fn solve_part_2(operations: &Vec<LightChangeOperation>) -> i64 {
     // 1000x1000 grid starts with all lights having brightness of 0
-    let mut light_matrix: [[i64; 1000]; 1000] = [[0; 1000]; 1000];
+    let mut light_grid: Grid<i64> = Grid::new(1000, 1000, 0);
     let mut total_brightness: i64 = 0;
     // Process each light change operation
     for op in operations {
         // Iterate over each light covered by the current operation
-        for y in op.top_left.1..=op.bot_right.1 {
-            for x in op.top_left.0..=op.bot_right.0 {
-                // Determine amount by which to adjust brightness of current light
-                let delta = match op.i_type {
-                    LightInstruction::TurnOn => 1,
-                    LightInstruction::TurnOff => {
-                        if light_matrix[y][x] > 0 {
-                            -1
-                        } else {
-                            0
-                        }
-                    },
-                    LightInstruction::Toggle => 2,
-                };
-                // Adjust current light and total brightness
-                light_matrix[y][x] += delta;
-                total_brightness += delta;
-            }
+        for (_, light) in light_grid.iter_region_mut(&op.region) {
+            // Determine amount by which to adjust brightness of current light
+            let delta = match op.i_type {
+                LightInstruction::TurnOn => 1,
+                LightInstruction::TurnOff => {
+                    if *light > 0 {
+                        -1
+                    } else {
+                        0
+                    }
+                },
+                LightInstruction::Toggle => 2,
+            };
+            // Adjust current light and total brightness
+            *light += delta;
+            total_brightness += delta;
         }
     }
     return total_brightness;
 }
 
+/// Solution for day 6, for use through the crate's solution registry.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    type Input = Vec<LightChangeOperation>;
+
+    fn parse(raw_input: &str) -> Result<Vec<LightChangeOperation>, ParseError> {
+        return generate_input(raw_input);
+    }
+
+    fn part1(input: &Vec<LightChangeOperation>) -> Answer {
+        return solve_part_1(input).into();
+    }
+
+    fn part2(input: &Vec<LightChangeOperation>) -> Answer {
+        return solve_part_2(input).into();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,21 +170,51 @@ mod tests {
 
     #[test]
     fn test_d06_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day6.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day6.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!(377891, result);
     }
 
-    /// NOTE: this test will overflow stack of default-size test thread (8 MiB). Need to increase
-    /// default test stack size using:
-    /// RUST_MIN_STACK=20000000 cargo test
     #[test]
     fn test_d06_p2_proper() {
-        println!("!!!! 01");
-        let input = generate_input(&read_to_string("./input/2015/day6.txt").unwrap());
-        println!("!!!! 02");
+        let input = generate_input(&read_to_string("./input/2015/day6.txt").unwrap()).unwrap();
         let result = solve_part_2(&input);
-        println!("!!!! 03");
         assert_eq!(14110788, result);
     }
+
+    #[test]
+    fn test_d06_input_invalid() {
+        let err = generate_input("toggle 0,0 through 999,0\nturn on 0,0 to 1,1").unwrap_err();
+        assert_eq!((2, 1), (err.get_line(), err.get_column()));
+        let err = generate_input("turn off 0,0 through 1000,5").unwrap_err();
+        assert_eq!((1, 22), (err.get_line(), err.get_column()));
+        assert_eq!("1000", err.get_text());
+        let err = generate_input("\tturn off 0,0 through 1000,5  ").unwrap_err();
+        assert_eq!((1, 23), (err.get_line(), err.get_column()));
+        let err = generate_input("turn on 5,5 through 4,9").unwrap_err();
+        assert_eq!("4,9", err.get_text());
+    }
+
+    #[test]
+    fn test_d06_p1_examples() {
+        let examples = [
+            ("turn on 0,0 through 999,999", 1000000),
+            ("toggle 0,0 through 999,0", 1000),
+            ("turn off 499,499 through 500,500", 0),
+            ("turn on 0,0 through 999,999\nturn off 499,499 through 500,500", 999996),
+        ];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_1(&input), "input: {}", raw_input);
+        }
+    }
+
+    #[test]
+    fn test_d06_p2_examples() {
+        let examples = [("turn on 0,0 through 0,0", 1), ("toggle 0,0 through 999,999", 2000000)];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_2(&input), "input: {}", raw_input);
+        }
+    }
 }
@@ -1,10 +1,13 @@
-use regex::Regex;
+use regex::{Captures, Regex};
+use super::error::ParseError;
+use super::solution::{Answer, Solution};
+use super::utils::input::PuzzleInput;
 use std::collections::HashMap;
 
 /// Defines the different instructions and their variants based on operand types.
 /// Possible instructions are: INPUT, AND, OR, LSHIFT, RSHIFT, NOT.
 #[derive(Clone, Debug)]
-enum Instruction {
+pub enum Instruction {
     InputWire {
         wire_id: String,
     },
@@ -59,153 +62,150 @@ enum Instruction {
     },
 }
 
-fn parse_input_wire_line(regex: &Regex, line: &str, circuit_wires: &mut HashMap<String, Instruction>) {
-    let captures = regex.captures(line).unwrap();
-    let output_wire = captures[2].to_string();
-    let wire_id = captures[1].to_string();
-    let instruction = Instruction::InputWire { wire_id };
-    circuit_wires.insert(output_wire, instruction);
+impl Instruction {
+    /// Gets the IDs of the wires from which the instruction takes its input signals.
+    fn get_input_wires(&self) -> Vec<&str> {
+        match self {
+            Instruction::InputWire { wire_id }
+            | Instruction::AndWireInput { wire_id, .. }
+            | Instruction::OrWireInput { wire_id, .. }
+            | Instruction::LshiftWire { wire_id, .. }
+            | Instruction::RshiftWire { wire_id, .. }
+            | Instruction::NotWire { wire_id } => vec![wire_id],
+            Instruction::AndWires { l_wire_id, r_wire_id }
+            | Instruction::OrWires { l_wire_id, r_wire_id } => vec![l_wire_id, r_wire_id],
+            _ => vec![],
+        }
+    }
 }
 
-fn parse_input_value_line(regex: &Regex, line: &str, circuit_wires: &mut HashMap<String, Instruction>) {
-    let captures = regex.captures(line).unwrap();
-    let output_wire = captures[2].to_string();
-    let input_value = captures[1].parse::<u16>().unwrap();
-    let instruction = Instruction::InputValue { input_value };
-    circuit_wires.insert(output_wire, instruction);
+/// Operand of a gate, either the signal carried by a wire or a literal signal value.
+enum Operand {
+    Wire(String),
+    Value(u16),
 }
 
-fn parse_and_line(regex: &Regex, line: &str, circuit_wires: &mut HashMap<String, Instruction>) {
-    let captures = regex.captures(line).unwrap();
-    let output_wire = captures[3].to_string();
-    if captures[1].parse::<u16>().is_ok() && captures[2].parse::<u16>().is_ok() {
-        let l_input_value = captures[1].parse::<u16>().unwrap();
-        let r_input_value = captures[2].parse::<u16>().unwrap();
-        let instruction = Instruction::AndInputs {
-            l_input_value,
-            r_input_value,
-        };
-        circuit_wires.insert(output_wire, instruction);
-    } else if captures[1].parse::<u16>().is_ok() && !captures[2].parse::<u16>().is_ok() {
-        let input_value = captures[1].parse::<u16>().unwrap();
-        let wire_id = captures[2].to_string();
-        let instruction = Instruction::AndWireInput {
-            wire_id,
-            input_value,
-        };
-        circuit_wires.insert(output_wire, instruction);
-    } else if !captures[1].parse::<u16>().is_ok() && captures[2].parse::<u16>().is_ok() {
-        let input_value = captures[2].parse::<u16>().unwrap();
-        let wire_id = captures[1].to_string();
-        let instruction = Instruction::AndWireInput {
-            wire_id,
-            input_value,
-        };
-        circuit_wires.insert(output_wire, instruction);
-    } else {
-        let l_wire_id = captures[1].to_string();
-        let r_wire_id = captures[2].to_string();
-        let instruction = Instruction::AndWires {
-            l_wire_id,
-            r_wire_id,
-        };
-        circuit_wires.insert(output_wire, instruction);
+/// Parses the operand held in the given capture group of the line.
+fn parse_operand(captures: &Captures, group: usize, line_num: usize) -> Result<Operand, ParseError> {
+    let field = captures.get(group).unwrap();
+    if !field.as_str().starts_with(|c: char| c.is_ascii_digit()) {
+        return Ok(Operand::Wire(field.as_str().to_string()));
+    }
+    match field.as_str().parse::<u16>() {
+        Ok(value) => return Ok(Operand::Value(value)),
+        Err(_) => {
+            return Err(ParseError::new(
+                7,
+                line_num,
+                field.start() + 1,
+                field.as_str(),
+                "a signal value in the range 0-65535",
+            ))
+        }
     }
 }
 
-fn parse_or_line(regex: &Regex, line: &str, circuit_wires: &mut HashMap<String, Instruction>) {
-    let captures = regex.captures(line).unwrap();
-    let output_wire = captures[3].to_string();
-    if captures[1].parse::<u16>().is_ok() && captures[2].parse::<u16>().is_ok() {
-        let l_input_value = captures[1].parse::<u16>().unwrap();
-        let r_input_value = captures[2].parse::<u16>().unwrap();
-        let instruction = Instruction::OrInputs {
-            l_input_value,
-            r_input_value,
-        };
-        circuit_wires.insert(output_wire, instruction);
-    } else if captures[1].parse::<u16>().is_ok() && !captures[2].parse::<u16>().is_ok() {
-        let input_value = captures[1].parse::<u16>().unwrap();
-        let wire_id = captures[2].to_string();
-        let instruction = Instruction::OrWireInput {
-            wire_id,
-            input_value,
-        };
-        circuit_wires.insert(output_wire, instruction);
-    } else if !captures[1].parse::<u16>().is_ok() && captures[2].parse::<u16>().is_ok() {
-        let input_value = captures[2].parse::<u16>().unwrap();
-        let wire_id = captures[1].to_string();
-        let instruction = Instruction::OrWireInput {
-            wire_id,
-            input_value,
-        };
-        circuit_wires.insert(output_wire, instruction);
-    } else {
-        let l_wire_id = captures[1].to_string();
-        let r_wire_id = captures[2].to_string();
-        let instruction = Instruction::OrWires {
-            l_wire_id,
-            r_wire_id,
-        };
-        circuit_wires.insert(output_wire, instruction);
+fn parse_input_line(captures: &Captures, line_num: usize) -> Result<Instruction, ParseError> {
+    match parse_operand(captures, 1, line_num)? {
+        Operand::Wire(wire_id) => return Ok(Instruction::InputWire { wire_id }),
+        Operand::Value(input_value) => return Ok(Instruction::InputValue { input_value }),
     }
 }
 
-fn parse_lshift_line(regex: &Regex, line: &str, circuit_wires: &mut HashMap<String, Instruction>) {
-    let captures = regex.captures(line).unwrap();
-    let output_wire = captures[3].to_string();
-    if captures[1].parse::<u16>().is_ok() {
-        let input_value = captures[1].parse::<u16>().unwrap();
-        let shift_value = captures[2].parse::<u16>().unwrap();
-        let instruction = Instruction::LshiftInput {
-            input_value,
-            shift_value,
-        };
-        circuit_wires.insert(output_wire, instruction);
-    } else {
-        let wire_id = captures[1].to_string();
-        let shift_value = captures[2].parse::<u16>().unwrap();
-        let instruction = Instruction::LshiftWire {
-            wire_id,
-            shift_value,
-        };
-        circuit_wires.insert(output_wire, instruction);
+fn parse_and_line(captures: &Captures, line_num: usize) -> Result<Instruction, ParseError> {
+    let left = parse_operand(captures, 1, line_num)?;
+    let right = parse_operand(captures, 2, line_num)?;
+    match (left, right) {
+        (Operand::Value(l_input_value), Operand::Value(r_input_value)) => {
+            return Ok(Instruction::AndInputs {
+                l_input_value,
+                r_input_value,
+            })
+        }
+        (Operand::Value(input_value), Operand::Wire(wire_id))
+        | (Operand::Wire(wire_id), Operand::Value(input_value)) => {
+            return Ok(Instruction::AndWireInput {
+                wire_id,
+                input_value,
+            })
+        }
+        (Operand::Wire(l_wire_id), Operand::Wire(r_wire_id)) => {
+            return Ok(Instruction::AndWires {
+                l_wire_id,
+                r_wire_id,
+            })
+        }
     }
 }
 
-fn parse_rshift_line(regex: &Regex, line: &str, circuit_wires: &mut HashMap<String, Instruction>) {
-    let captures = regex.captures(line).unwrap();
-    let output_wire = captures[3].to_string();
-    if captures[1].parse::<u16>().is_ok() {
-        let input_value = captures[1].parse::<u16>().unwrap();
-        let shift_value = captures[2].parse::<u16>().unwrap();
-        let instruction = Instruction::RshiftInput {
-            input_value,
-            shift_value,
-        };
-        circuit_wires.insert(output_wire, instruction);
-    } else {
-        let wire_id = captures[1].to_string();
-        let shift_value = captures[2].parse::<u16>().unwrap();
-        let instruction = Instruction::RshiftWire {
-            wire_id,
-            shift_value,
-        };
-        circuit_wires.insert(output_wire, instruction);
+fn parse_or_line(captures: &Captures, line_num: usize) -> Result<Instruction, ParseError> {
+    let left = parse_operand(captures, 1, line_num)?;
+    let right = parse_operand(captures, 2, line_num)?;
+    match (left, right) {
+        (Operand::Value(l_input_value), Operand::Value(r_input_value)) => {
+            return Ok(Instruction::OrInputs {
+                l_input_value,
+                r_input_value,
+            })
+        }
+        (Operand::Value(input_value), Operand::Wire(wire_id))
+        | (Operand::Wire(wire_id), Operand::Value(input_value)) => {
+            return Ok(Instruction::OrWireInput {
+                wire_id,
+                input_value,
+            })
+        }
+        (Operand::Wire(l_wire_id), Operand::Wire(r_wire_id)) => {
+            return Ok(Instruction::OrWires {
+                l_wire_id,
+                r_wire_id,
+            })
+        }
+    }
+}
+
+fn parse_lshift_line(captures: &Captures, line_num: usize) -> Result<Instruction, ParseError> {
+    let shift_value = match parse_operand(captures, 2, line_num)? {
+        Operand::Value(shift_value) if shift_value < 16 => shift_value,
+        _ => return Err(shift_error(captures, line_num)),
+    };
+    match parse_operand(captures, 1, line_num)? {
+        Operand::Wire(wire_id) => return Ok(Instruction::LshiftWire { wire_id, shift_value }),
+        Operand::Value(input_value) => {
+            return Ok(Instruction::LshiftInput {
+                input_value,
+                shift_value,
+            })
+        }
     }
 }
 
-fn parse_not_line(regex: &Regex, line: &str, circuit_wires: &mut HashMap<String, Instruction>) {
-    let captures = regex.captures(line).unwrap();
-    let output_wire = captures[2].to_string();
-    if captures[1].parse::<u16>().is_ok() {
-        let input_value = captures[1].parse::<u16>().unwrap();
-        let instruction = Instruction::NotInput { input_value };
-        circuit_wires.insert(output_wire, instruction);
-    } else {
-        let wire_id = captures[1].to_string();
-        let instruction = Instruction::NotWire { wire_id };
-        circuit_wires.insert(output_wire, instruction);
+fn parse_rshift_line(captures: &Captures, line_num: usize) -> Result<Instruction, ParseError> {
+    let shift_value = match parse_operand(captures, 2, line_num)? {
+        Operand::Value(shift_value) if shift_value < 16 => shift_value,
+        _ => return Err(shift_error(captures, line_num)),
+    };
+    match parse_operand(captures, 1, line_num)? {
+        Operand::Wire(wire_id) => return Ok(Instruction::RshiftWire { wire_id, shift_value }),
+        Operand::Value(input_value) => {
+            return Ok(Instruction::RshiftInput {
+                input_value,
+                shift_value,
+            })
+        }
+    }
+}
+
+/// Creates the error reported when a shift amount would shift out every bit of a 16-bit signal.
+fn shift_error(captures: &Captures, line_num: usize) -> ParseError {
+    let field = captures.get(2).unwrap();
+    return ParseError::new(7, line_num, field.start() + 1, field.as_str(), "a shift amount in the range 0-15");
+}
+
+fn parse_not_line(captures: &Captures, line_num: usize) -> Result<Instruction, ParseError> {
+    match parse_operand(captures, 1, line_num)? {
+        Operand::Wire(wire_id) => return Ok(Instruction::NotWire { wire_id }),
+        Operand::Value(input_value) => return Ok(Instruction::NotInput { input_value }),
     }
 }
 
@@ -296,48 +296,134 @@ fn evaluate_instruction(
     known_values.insert(target_wire_id.to_string(), output);
 }
 
+/// Finds the (0-based) index at which the wire is first used as a token in the line.
+fn find_wire_index(line: &str, wire_id: &str) -> usize {
+    let mut index = 0;
+    for token in line.split(' ') {
+        if token == wire_id {
+            break;
+        }
+        index += token.len() + 1;
+    }
+    return index;
+}
+
+/// Finds the first wire whose signal depends on itself, searching from each wire in the given
+/// order. Gives the wire along with the input wire that closes the loop back to it.
+fn find_wire_loop<'a>(circuit_wires: &'a HashMap<String, Instruction>, wires: &[&'a String]) -> Option<(&'a str, &'a str)> {
+    // Wires still being followed map to false, and wires already found to be loop-free to true
+    let mut finished: HashMap<&str, bool> = HashMap::new();
+    for wire in wires {
+        if finished.contains_key(wire.as_str()) {
+            continue;
+        }
+        finished.insert(wire, false);
+        let mut stack: Vec<(&str, usize)> = vec![(wire, 0)];
+        while let Some(&(current, index)) = stack.last() {
+            let input_wires = circuit_wires[current].get_input_wires();
+            if index == input_wires.len() {
+                finished.insert(current, true);
+                stack.pop();
+                continue;
+            }
+            stack.last_mut().unwrap().1 += 1;
+            let input_wire = input_wires[index];
+            match finished.get(input_wire) {
+                Some(false) => return Some((current, input_wire)),
+                Some(true) => (),
+                None => {
+                    finished.insert(input_wire, false);
+                    stack.push((input_wire, 0));
+                }
+            }
+        }
+    }
+    return None;
+}
+
 #[aoc_generator(day7)]
-fn generate_input(raw_input: &str) -> HashMap<String, Instruction> {
-    // Record the input being provided to each wire - ASSUMED that each wire has only one input!
+pub fn generate_input(raw_input: &str) -> Result<HashMap<String, Instruction>, ParseError> {
+    // Record the input being provided to each wire - each wire can only have one input!
     let mut circuit_wires: HashMap<String, Instruction> = HashMap::new();
+    // Record the line on which each wire had its input defined, for reporting errors
+    let mut wire_lines: HashMap<String, (usize, String)> = HashMap::new();
     // Define regexes to match each of the instruction variants
-    let input_value_regex = Regex::new(r"^(\d+) -> ([[:alpha:]]+)$").unwrap();
-    let input_wire_regex = Regex::new(r"^([[:alpha:]]+) -> ([[:alpha:]])$").unwrap();
-    let and_regex = Regex::new(r"^(.+) AND (.+) -> ([[:alpha:]]+)$").unwrap();
-    let or_regex = Regex::new(r"^(.+) OR (.+) -> ([[:alpha:]]+)$").unwrap();
-    let leftshift_regex = Regex::new(r"^(.+) LSHIFT (\d+) -> ([[:alpha:]]+)$").unwrap();
-    let rightshift_regex = Regex::new(r"^(.+) RSHIFT (\d+) -> ([[:alpha:]]+)$").unwrap();
-    let not_regex = Regex::new(r"^NOT (.+) -> ([[:alpha:]]+)$").unwrap();
-    for line in raw_input.lines() {
-        // Trim leading and trailing whitespace, then ignore empty lines
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
+    let input_regex = Regex::new(r"^(\d+|[[:alpha:]]+) -> ([[:alpha:]]+)$").unwrap();
+    let and_regex = Regex::new(r"^(\d+|[[:alpha:]]+) AND (\d+|[[:alpha:]]+) -> ([[:alpha:]]+)$").unwrap();
+    let or_regex = Regex::new(r"^(\d+|[[:alpha:]]+) OR (\d+|[[:alpha:]]+) -> ([[:alpha:]]+)$").unwrap();
+    let leftshift_regex = Regex::new(r"^(\d+|[[:alpha:]]+) LSHIFT (\d+) -> ([[:alpha:]]+)$").unwrap();
+    let rightshift_regex = Regex::new(r"^(\d+|[[:alpha:]]+) RSHIFT (\d+) -> ([[:alpha:]]+)$").unwrap();
+    let not_regex = Regex::new(r"^NOT (\d+|[[:alpha:]]+) -> ([[:alpha:]]+)$").unwrap();
+    let input = PuzzleInput::new(raw_input);
+    for (line_num, line) in input.get_lines() {
         // Parse current line depending on type of instruction
-        if input_wire_regex.is_match(line) {
-            parse_input_wire_line(&input_wire_regex, line, &mut circuit_wires);
-        } else if input_value_regex.is_match(line) {
-            parse_input_value_line(&input_value_regex, line, &mut circuit_wires);
-        } else if and_regex.is_match(line) {
-            parse_and_line(&and_regex, line, &mut circuit_wires);
-        } else if or_regex.is_match(line) {
-            parse_or_line(&or_regex, line, &mut circuit_wires);
-        } else if leftshift_regex.is_match(line) {
-            parse_lshift_line(&leftshift_regex, line, &mut circuit_wires);
-        } else if rightshift_regex.is_match(line) {
-            parse_rshift_line(&rightshift_regex, line, &mut circuit_wires);
-        } else if not_regex.is_match(line) {
-            parse_not_line(&not_regex, line, &mut circuit_wires);
+        // Columns found by the line parsers count from the start of the trimmed line
+        let (captures, parsed) = if let Some(captures) = input_regex.captures(line) {
+            let parsed = parse_input_line(&captures, line_num);
+            (captures, parsed)
+        } else if let Some(captures) = and_regex.captures(line) {
+            let parsed = parse_and_line(&captures, line_num);
+            (captures, parsed)
+        } else if let Some(captures) = or_regex.captures(line) {
+            let parsed = parse_or_line(&captures, line_num);
+            (captures, parsed)
+        } else if let Some(captures) = leftshift_regex.captures(line) {
+            let parsed = parse_lshift_line(&captures, line_num);
+            (captures, parsed)
+        } else if let Some(captures) = rightshift_regex.captures(line) {
+            let parsed = parse_rshift_line(&captures, line_num);
+            (captures, parsed)
+        } else if let Some(captures) = not_regex.captures(line) {
+            let parsed = parse_not_line(&captures, line_num);
+            (captures, parsed)
         } else {
-            panic!("Day 7 - input line found with invalid format!");
+            let column = input.get_column(line_num, 0);
+            return Err(ParseError::new(7, line_num, column, line, "a wire instruction ending in \"-> <wire>\""));
+        };
+        let instruction = parsed.map_err(|err| input.locate_error(err))?;
+        // Output wire is always the last capture group
+        let output = captures.get(captures.len() - 1).unwrap();
+        if wire_lines.contains_key(output.as_str()) {
+            return Err(ParseError::new(
+                7,
+                line_num,
+                input.get_column(line_num, output.start()),
+                output.as_str(),
+                "a wire that has not already been given an input",
+            ));
         }
+        wire_lines.insert(output.as_str().to_string(), (line_num, line.to_string()));
+        circuit_wires.insert(output.as_str().to_string(), instruction);
+    }
+    // Check that every wire used as an input has its own signal source, going through the wires in
+    // input order so the first offending line is always the one reported
+    let mut output_wires = circuit_wires.keys().collect::<Vec<&String>>();
+    output_wires.sort_by_key(|wire| wire_lines[wire.as_str()].0);
+    for output_wire in output_wires.iter() {
+        for wire_id in circuit_wires[*output_wire].get_input_wires() {
+            if !circuit_wires.contains_key(wire_id) {
+                let (line_num, line) = &wire_lines[output_wire.as_str()];
+                let column = input.get_column(*line_num, find_wire_index(line, wire_id));
+                return Err(ParseError::new(7, *line_num, column, wire_id, "a wire that is given an input"));
+            }
+        }
+    }
+    // A wire whose signal depends on itself can never be evaluated
+    if let Some((output_wire, wire_id)) = find_wire_loop(&circuit_wires, &output_wires) {
+        let (line_num, line) = &wire_lines[output_wire];
+        let column = input.get_column(*line_num, find_wire_index(line, wire_id));
+        return Err(ParseError::new(7, *line_num, column, wire_id, "a wire whose signal does not depend on itself"));
+    }
+    // Both parts find the signal on wire "a", so it must be given an input
+    if !circuit_wires.contains_key("a") {
+        let line_num = input.get_text().lines().count() + 1;
+        return Err(ParseError::new(7, line_num, 1, "", "a line giving wire \"a\" its input"));
     }
-    return circuit_wires;
+    return Ok(circuit_wires);
 }
 
 #[aoc(day7, part1)]
-fn solve_part_1(circuit_wires: &HashMap<String, Instruction>) -> u16 {
+pub fn solve_part_1(circuit_wires: &HashMap<String, Instruction>) -> u16 {
     // Keep record of what wire values are known
     let mut known_values: HashMap<String, u16> = HashMap::new();
     // Recursively evaluate wire values, until the value on wire "a" is known
@@ -347,7 +433,7 @@ fn solve_part_1(circuit_wires: &HashMap<String, Instruction>) -> u16 {
 }
 
 #[aoc(day7, part2)]
-fn solve_part_2(circuit_wires: &HashMap<String, Instruction>) -> u16 {
+pub fn solve_part_2(circuit_wires: &HashMap<String, Instruction>) -> u16 {
     // Get the original value of signal on wire "a"
     let original_wire_a = solve_part_1(circuit_wires);
     // Override the input value for wire "b"
@@ -360,6 +446,26 @@ fn solve_part_2(circuit_wires: &HashMap<String, Instruction>) -> u16 {
     return *known_values.get("a").unwrap();
 }
 
+/// Solution for day 7, for use through the crate's solution registry.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    type Input = HashMap<String, Instruction>;
+
+    fn parse(raw_input: &str) -> Result<HashMap<String, Instruction>, ParseError> {
+        return generate_input(raw_input);
+    }
+
+    fn part1(input: &HashMap<String, Instruction>) -> Answer {
+        return solve_part_1(input).into();
+    }
+
+    fn part2(input: &HashMap<String, Instruction>) -> Answer {
+        return solve_part_2(input).into();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -367,15 +473,66 @@ mod tests {
 
     #[test]
     fn test_d07_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day7.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day7.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!(956, result);
     }
 
     #[test]
     fn test_d07_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day7.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day7.txt").unwrap()).unwrap();
         let result = solve_part_2(&input);
         assert_eq!(40149, result);
     }
+
+    #[test]
+    fn test_d07_input_invalid() {
+        let err = generate_input("123 -> x\nx AND y -> z\n").unwrap_err();
+        assert_eq!((2, 7), (err.get_line(), err.get_column()));
+        assert_eq!("y", err.get_text());
+        let err = generate_input("123 -> x\n  x AND y -> z\n").unwrap_err();
+        assert_eq!((2, 9), (err.get_line(), err.get_column()));
+        let err = generate_input("123 -> x\nx LSHIFT 16 -> y\n").unwrap_err();
+        assert_eq!((2, 10), (err.get_line(), err.get_column()));
+        let err = generate_input("123 -> x\nx XOR 1 -> y\n").unwrap_err();
+        assert_eq!((2, 1), (err.get_line(), err.get_column()));
+        assert!(generate_input("1 -> x\n2 -> x\n").is_err());
+        // With several unresolved wires, the first line using one is reported every time
+        for _ in 0..10 {
+            let err = generate_input("x -> b\nc -> d\ne -> f\n").unwrap_err();
+            assert_eq!((1, "x"), (err.get_line(), err.get_text()));
+        }
+        // Wire "a" must be given an input, and no wire may depend on its own signal
+        let err = generate_input("123 -> x\nx -> y\n").unwrap_err();
+        assert_eq!((3, 1, ""), (err.get_line(), err.get_column(), err.get_text()));
+        let err = generate_input("1 -> c\nb AND c -> a\na OR c -> d\nd -> b\n").unwrap_err();
+        assert_eq!((3, 1, "a"), (err.get_line(), err.get_column(), err.get_text()));
+        let err = generate_input("a -> a\n").unwrap_err();
+        assert_eq!((1, 1, "a"), (err.get_line(), err.get_column(), err.get_text()));
+    }
+
+    #[test]
+    fn test_d07_p1_examples() {
+        let raw_input = "123 -> x\n456 -> y\nx AND y -> d\nx OR y -> e\nx LSHIFT 2 -> f\n\
+            y RSHIFT 2 -> g\nNOT x -> h\nNOT y -> i";
+        // The sample circuit has no wire "a", which the puzzle input always has
+        assert!(generate_input(raw_input).is_err());
+        let raw_input = format!("{}\nd -> a", raw_input);
+        let examples = [
+            ("d", 72),
+            ("e", 507),
+            ("f", 492),
+            ("g", 114),
+            ("h", 65412),
+            ("i", 65079),
+            ("x", 123),
+            ("y", 456),
+        ];
+        let circuit_wires = generate_input(&raw_input).unwrap();
+        for (wire_id, expected) in examples.iter() {
+            let mut known_values: HashMap<String, u16> = HashMap::new();
+            evaluate_instruction(wire_id, &circuit_wires, &mut known_values);
+            assert_eq!(*expected, known_values[*wire_id], "wire: {}", wire_id);
+        }
+    }
 }
@@ -1,16 +1,59 @@
 use std::str;
+use super::error::ParseError;
+use super::solution::{Answer, Solution};
+use super::utils::input::PuzzleInput;
 
 #[aoc_generator(day8)]
-fn generate_input(raw_input: &str) -> Vec<String> {
-    return raw_input
-        .lines()
-        .map(|x| x.trim().to_string())
-        .filter(|x| !x.is_empty())
-        .collect::<Vec<String>>();
+pub fn generate_input(raw_input: &str) -> Result<Vec<String>, ParseError> {
+    let mut file_strings: Vec<String> = vec![];
+    let input = PuzzleInput::new(raw_input);
+    for (line_num, line) in input.get_lines() {
+        validate_string_literal(line, line_num).map_err(|err| input.locate_error(err))?;
+        file_strings.push(line.to_string());
+    }
+    return Ok(file_strings);
+}
+
+/// Checks that the line is a double-quoted string literal with only valid escape sequences.
+fn validate_string_literal(line: &str, line_num: usize) -> Result<(), ParseError> {
+    let chars = line.chars().collect::<Vec<char>>();
+    if chars.len() < 2 || chars[0] != '"' || chars[chars.len() - 1] != '"' {
+        return Err(ParseError::new(8, line_num, 1, line, "a string literal enclosed in double quotes"));
+    }
+    // Check the contents between the enclosing quotes
+    let mut i = 1;
+    while i < chars.len() - 1 {
+        match chars[i] {
+            '\\' => {
+                let escape = chars[i..chars.len() - 1].iter().take(4).collect::<String>();
+                if escape.starts_with("\\\\") || escape.starts_with("\\\"") {
+                    i += 2;
+                } else if escape.starts_with("\\x")
+                    && escape.chars().count() == 4
+                    && escape.chars().skip(2).all(|c| c.is_ascii_hexdigit())
+                {
+                    i += 4;
+                } else {
+                    return Err(ParseError::new(
+                        8,
+                        line_num,
+                        i + 1,
+                        &escape,
+                        "an escape sequence of \\\\, \\\" or \\x followed by two hex digits",
+                    ));
+                }
+            }
+            '"' => {
+                return Err(ParseError::new(8, line_num, i + 1, "\"", "double quotes inside a string to be escaped"));
+            }
+            _ => i += 1,
+        }
+    }
+    return Ok(());
 }
 
 #[aoc(day8, part1)]
-fn solve_part_1(file_strings: &Vec<String>) -> usize {
+pub fn solve_part_1(file_strings: &Vec<String>) -> usize {
     let mut total_code_length: usize = 0;
     let mut total_mem_length: usize = 0;
     for s in file_strings {
@@ -43,7 +86,7 @@ fn solve_part_1(file_strings: &Vec<String>) -> usize {
 }
 
 #[aoc(day8, part2)]
-fn solve_part_2(file_strings: &Vec<String>) -> usize {
+pub fn solve_part_2(file_strings: &Vec<String>) -> usize {
     let mut total_code_length: usize = 0;
     let mut total_mem_length: usize = 0;
     for s in file_strings {
@@ -79,6 +122,26 @@ fn solve_part_2(file_strings: &Vec<String>) -> usize {
     return total_code_length - total_mem_length;
 }
 
+/// Solution for day 8, for use through the crate's solution registry.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    type Input = Vec<String>;
+
+    fn parse(raw_input: &str) -> Result<Vec<String>, ParseError> {
+        return generate_input(raw_input);
+    }
+
+    fn part1(input: &Vec<String>) -> Answer {
+        return solve_part_1(input).into();
+    }
+
+    fn part2(input: &Vec<String>) -> Answer {
+        return solve_part_2(input).into();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,15 +149,46 @@ mod tests {
 
     #[test]
     fn test_d08_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day8.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day8.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!(1371, result);
     }
 
     #[test]
     fn test_d08_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day8.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day8.txt").unwrap()).unwrap();
         let result = solve_part_2(&input);
         assert_eq!(2117, result);
     }
+
+    #[test]
+    fn test_d08_input_invalid() {
+        let err = generate_input("\"abc\"\n\"abc\\\"").unwrap_err();
+        assert_eq!((2, 5), (err.get_line(), err.get_column()));
+        let err = generate_input("\"\\x4g\"").unwrap_err();
+        assert_eq!((1, 2), (err.get_line(), err.get_column()));
+        assert!(generate_input("abc").is_err());
+    }
+
+    #[test]
+    fn test_d08_p1_examples() {
+        let examples = [(r#""""#, 2), (r#""abc""#, 2), (r#""aaa\"aaa""#, 3), (r#""\x27""#, 5)];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_1(&input), "input: {}", raw_input);
+        }
+        let input = generate_input(&read_to_string("./input/2015/test/t_day_08_01.txt").unwrap()).unwrap();
+        assert_eq!(12, solve_part_1(&input));
+    }
+
+    #[test]
+    fn test_d08_p2_examples() {
+        let examples = [(r#""""#, 4), (r#""abc""#, 4), (r#""aaa\"aaa""#, 6), (r#""\x27""#, 5)];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_2(&input), "input: {}", raw_input);
+        }
+        let input = generate_input(&read_to_string("./input/2015/test/t_day_08_01.txt").unwrap()).unwrap();
+        assert_eq!(19, solve_part_2(&input));
+    }
 }
@@ -1,98 +1,465 @@
-use std::collections::HashMap;
-use itertools::Itertools;
+use std::error::Error;
+use std::fmt;
 use regex::Regex;
+use super::error::ParseError;
+use super::solution::{Answer, Solution};
+use super::utils::graph::{NodeId, Objective, Route, RouteAlgorithm, RouteConstraints, TiedRoutes, WeightedGraph};
+use super::utils::input::PuzzleInput;
 
 #[aoc_generator(day9)]
-fn generate_input(raw_input: &str) -> HashMap<String, HashMap<String, u64>> {
-    // Parse input as a graph, with each node connected to others by undirected edges
-    let mut graph: HashMap<String, HashMap<String, u64>> = HashMap::new();
-    let line_regex = Regex::new(r"^([[:alpha:]]+) to ([[:alpha:]]+) = (\d+)$").unwrap();
-    for line in raw_input.lines() {
-        // Trim leading and trailing whitespace from each line, then ignore empty lines
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
-        // Only parse lines that match the required format - otherwise terminate program.
-        if let Some(captures) = line_regex.captures(line) {
-            let city_a = captures[1].to_string();
-            let city_b = captures[2].to_string();
-            let distance = captures[3].parse::<u64>().unwrap();
-            // Add new key to graph if needed
-            if !graph.contains_key(&city_a) {
-                graph.insert(city_a.to_string(), HashMap::new());
+pub fn generate_input(raw_input: &str) -> Result<WeightedGraph, ParseError> {
+    // Parse input as a graph, with "to" lines giving undirected edges and "->" lines giving
+    // one-way edges. Cities need not be connected to every other city.
+    let mut graph = WeightedGraph::new();
+    let line_regex = Regex::new(r"^([[:alpha:]]+) (to|->) ([[:alpha:]]+) = (\d+)$").unwrap();
+    let input = PuzzleInput::new(raw_input);
+    for (line_num, line) in input.get_lines() {
+        // Only parse lines that match the required format
+        let captures = match line_regex.captures(line) {
+            Some(captures) => captures,
+            None => {
+                return Err(ParseError::new(
+                    9,
+                    line_num,
+                    input.get_column(line_num, 0),
+                    line,
+                    "\"<city> to <city> = <distance>\" or \"<city> -> <city> = <distance>\"",
+                ))
             }
-            if !graph.contains_key(&city_b) {
-                graph.insert(city_b.to_string(), HashMap::new());
+        };
+        let distance = match captures[4].parse::<u64>() {
+            Ok(distance) => distance,
+            Err(_) => {
+                let field = captures.get(4).unwrap();
+                return Err(ParseError::new(
+                    9,
+                    line_num,
+                    input.get_column(line_num, field.start()),
+                    field.as_str(),
+                    "a distance that fits in a u64",
+                ));
             }
-            // Connect the cities together
-            graph.get_mut(&city_a).unwrap().insert(city_b.to_string(), distance);
-            graph.get_mut(&city_b).unwrap().insert(city_a.to_string(), distance);
+        };
+        // Connect the cities together, adding them to the graph if needed
+        let city_a = graph.add_node(&captures[1]);
+        let city_b = graph.add_node(&captures[3]);
+        if &captures[2] == "->" {
+            graph.add_edge(city_a, city_b, distance);
         } else {
-            panic!("Day 9 - raw input line with bad format!");
+            graph.add_undirected_edge(city_a, city_b, distance);
         }
     }
-    return graph;
+    return Ok(graph);
 }
 
 #[aoc(day9, part1)]
-fn solve_part_1(graph: &HashMap<String, HashMap<String, u64>>) -> u64 {
-    // Input has eight cities, meaning 8! possible sequences - small enough to brute force
-    let cities = graph.keys().map(|x| x.to_string()).collect::<Vec<String>>();
-    // Track the minimum distance observed across each tested sequence
-    let mut min_distance = u64::MAX;
-    // All cities are connected to each other by undirected edges
-    for seq in cities.iter().permutations(cities.len()).unique() {
-        let mut seq_distance = 0;
-        // Add up the total distance for the current sequence
-        for i in 0..seq.len()-1 {
-            seq_distance += graph.get(seq[i]).unwrap().get(seq[i + 1]).unwrap();
+pub fn solve_part_1(graph: &WeightedGraph) -> Option<u64> {
+    return find_route_distance(graph, Objective::Shortest, RouteAlgorithm::HeldKarp);
+}
+
+#[aoc(day9, part2)]
+pub fn solve_part_2(graph: &WeightedGraph) -> Option<u64> {
+    return find_route_distance(graph, Objective::Longest, RouteAlgorithm::HeldKarp);
+}
+
+/// Finds the total distance of the best route visiting every city once, using the given
+/// algorithm, or `None` if missing or one-way legs leave no such route. Also gives `None` if there
+/// are more cities than the algorithm accepts. The puzzle solvers use Held-Karp, which handles up
+/// to 20 cities.
+pub fn find_route_distance(graph: &WeightedGraph, objective: Objective, algorithm: RouteAlgorithm) -> Option<u64> {
+    let route = graph.find_hamiltonian_path(objective, &RouteConstraints::default(), algorithm);
+    return route.map(|route| route.get_distance());
+}
+
+/// Finds the routes tied for the best total distance that visit every city once, using the given
+/// algorithm. At most `max_routes` of them are listed, though every tie is counted. A route is
+/// only tied with its reverse, which counts as a separate route, when every leg can be travelled
+/// back at the same distance, as with "to" legs. Routes using "->" legs need not be reversible.
+pub fn find_best_routes(graph: &WeightedGraph, objective: Objective, algorithm: RouteAlgorithm, max_routes: usize) -> BestRoutes {
+    let tied = graph.find_tied_hamiltonian_paths(objective, &RouteConstraints::default(), algorithm, max_routes);
+    return BestRoutes::from_tied_routes(graph, &tied);
+}
+
+/// Finds the total distance of the best route through the cities that follows the plan, using
+/// the given algorithm. Gives `None` if no route follows the plan, or an error if the plan names a
+/// city that is not in the graph.
+pub fn plan_route_distance(
+    graph: &WeightedGraph,
+    objective: Objective,
+    plan: &RoutePlan,
+    algorithm: RouteAlgorithm,
+) -> Result<Option<u64>, UnknownCityError> {
+    let route = graph.find_hamiltonian_path(objective, &plan.to_constraints(graph)?, algorithm);
+    return Ok(route.map(|route| route.get_distance()));
+}
+
+/// Finds the routes tied for the best total distance that follow the plan, using the given
+/// algorithm. At most `max_routes` of them are listed, though every tie is counted. Gives an error
+/// if the plan names a city that is not in the graph.
+pub fn plan_best_routes(
+    graph: &WeightedGraph,
+    objective: Objective,
+    plan: &RoutePlan,
+    algorithm: RouteAlgorithm,
+    max_routes: usize,
+) -> Result<BestRoutes, UnknownCityError> {
+    let tied = graph.find_tied_hamiltonian_paths(objective, &plan.to_constraints(graph)?, algorithm, max_routes);
+    return Ok(BestRoutes::from_tied_routes(graph, &tied));
+}
+
+/// Options for planning routes through the cities, such as for delivery rounds. The default plan
+/// is an open route with a free start and end that visits every city once, as in the puzzle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoutePlan {
+    /// Whether the route must return to the city it started from.
+    pub round_trip: bool,
+    /// City the route must start from, if any.
+    pub start: Option<String>,
+    /// City the route must finish at, if any. For a round trip this must be the start city.
+    pub end: Option<String>,
+    /// Cities that must be visited, or `None` to visit every city. The start and end cities are
+    /// always visited, while other cities are skipped, so each leg must be a direct one.
+    pub required: Option<Vec<String>>,
+}
+
+impl RoutePlan {
+    /// Converts the plan into constraints on routes through the graph.
+    fn to_constraints(&self, graph: &WeightedGraph) -> Result<RouteConstraints, UnknownCityError> {
+        let get_node = |city: &String| graph.get_node_id(city).ok_or_else(|| UnknownCityError::new(city));
+        let required = match &self.required {
+            Some(cities) => Some(cities.iter().map(get_node).collect::<Result<Vec<NodeId>, UnknownCityError>>()?),
+            None => None,
+        };
+        return Ok(RouteConstraints {
+            closed: self.round_trip,
+            start: self.start.as_ref().map(get_node).transpose()?,
+            end: self.end.as_ref().map(get_node).transpose()?,
+            required: required,
+        });
+    }
+}
+
+/// Error returned when a route plan names a city that is not in the distances.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownCityError {
+    city: String,
+}
+
+impl UnknownCityError {
+    /// Creates a new error for the city that could not be found.
+    pub fn new(city: &str) -> Self {
+        Self {
+            city: city.to_string(),
+        }
+    }
+
+    /// Gets the name of the city that could not be found.
+    pub fn get_city(&self) -> &str {
+        return &self.city;
+    }
+}
+
+impl fmt::Display for UnknownCityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no distances given for city {:?}", self.city)
+    }
+}
+
+impl Error for UnknownCityError {}
+
+/// Route visiting the cities in order, with the distance of each leg between them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CityRoute {
+    cities: Vec<String>,
+    leg_distances: Vec<u64>,
+}
+
+impl CityRoute {
+    /// Creates a new city route from a route through the graph.
+    fn from_route(graph: &WeightedGraph, route: &Route) -> Self {
+        let nodes = route.get_nodes();
+        Self {
+            cities: nodes.iter().map(|node| graph.get_label(*node).to_string()).collect::<Vec<String>>(),
+            leg_distances: nodes.windows(2).map(|leg| graph.get_weight(leg[0], leg[1]).unwrap()).collect::<Vec<u64>>(),
         }
-        // Check if a new minimum distance has been found
-        if seq_distance < min_distance {
-            min_distance = seq_distance;
+    }
+
+    /// Gets the names of the cities visited, in order.
+    pub fn get_cities(&self) -> &Vec<String> {
+        return &self.cities;
+    }
+
+    /// Gets the distance of each leg between consecutive cities, in order.
+    pub fn get_leg_distances(&self) -> &Vec<u64> {
+        return &self.leg_distances;
+    }
+
+    /// Gets the total distance travelled along the route.
+    pub fn get_total_distance(&self) -> u64 {
+        return self.leg_distances.iter().sum();
+    }
+}
+
+impl fmt::Display for CityRoute {
+    /// Formats the route as its cities in order followed by the leg distances, such as
+    /// "London -> Dublin -> Belfast = 605 (464 + 141)".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let legs = self.leg_distances.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        write!(f, "{} = {} ({})", self.cities.join(" -> "), self.get_total_distance(), legs.join(" + "))
+    }
+}
+
+/// Routes tied for the best total distance, of which only the first few may be listed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BestRoutes {
+    routes: Vec<CityRoute>,
+    count: u64,
+}
+
+impl BestRoutes {
+    /// Creates the best routes through the cities from the tied routes through the graph.
+    fn from_tied_routes(graph: &WeightedGraph, tied: &TiedRoutes) -> Self {
+        Self {
+            routes: tied.get_routes().iter().map(|route| CityRoute::from_route(graph, route)).collect::<Vec<CityRoute>>(),
+            count: tied.get_count(),
         }
     }
-    return min_distance;
+
+    /// Gets the routes listed, in order of the cities visited.
+    pub fn get_routes(&self) -> &Vec<CityRoute> {
+        return &self.routes;
+    }
+
+    /// Gets the total number of routes tied for the best distance, including those not listed.
+    pub fn get_count(&self) -> u64 {
+        return self.count;
+    }
 }
 
-#[aoc(day9, part2)]
-fn solve_part_2(graph: &HashMap<String, HashMap<String, u64>>) -> u64 {
-    // Input has eight cities, meaning 8! possible sequences - small enough to brute force
-    let cities = graph.keys().map(|x| x.to_string()).collect::<Vec<String>>();
-    // Track the maximum distance observed across each tested sequence
-    let mut max_distance = 0;
-    // All cities are connected to each other by undirected edges
-    for seq in cities.iter().permutations(cities.len()).unique() {
-        let mut seq_distance = 0;
-        // Add up the total distance for the current sequence
-        for i in 0..seq.len()-1 {
-            seq_distance += graph.get(seq[i]).unwrap().get(seq[i + 1]).unwrap();
+/// Largest number of tied routes listed when explaining an answer.
+const MAX_EXPLAINED_ROUTES: usize = 10;
+
+/// Answer given when no route visits every city.
+const NO_ROUTE: &str = "no route";
+
+/// Answer given when there are more cities than the route search used by the solvers accepts.
+const TOO_MANY_CITIES: &str = "too many cities";
+
+/// Checks whether the graph has more cities than the route search used by the solvers accepts.
+fn has_too_many_cities(graph: &WeightedGraph) -> bool {
+    return RouteAlgorithm::HeldKarp.get_max_nodes().is_some_and(|max_nodes| graph.get_node_count() > max_nodes);
+}
+
+/// Solution for day 9, for use through the crate's solution registry.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    type Input = WeightedGraph;
+
+    fn parse(raw_input: &str) -> Result<WeightedGraph, ParseError> {
+        return generate_input(raw_input);
+    }
+
+    fn part1(input: &WeightedGraph) -> Answer {
+        if has_too_many_cities(input) {
+            return Answer::from(TOO_MANY_CITIES);
+        }
+        return solve_part_1(input).map_or(Answer::from(NO_ROUTE), Answer::from);
+    }
+
+    fn part2(input: &WeightedGraph) -> Answer {
+        if has_too_many_cities(input) {
+            return Answer::from(TOO_MANY_CITIES);
+        }
+        return solve_part_2(input).map_or(Answer::from(NO_ROUTE), Answer::from);
+    }
+
+    fn explain(part: u32, input: &WeightedGraph) -> Option<String> {
+        if has_too_many_cities(input) {
+            return Some(String::from("too many cities to search for the best route"));
+        }
+        let objective = if part == 1 { Objective::Shortest } else { Objective::Longest };
+        let best = find_best_routes(input, objective, RouteAlgorithm::HeldKarp, MAX_EXPLAINED_ROUTES);
+        if best.get_routes().is_empty() {
+            return Some(String::from("no route visits every city"));
         }
-        // Check if a new maximum distance has been found
-        if seq_distance > max_distance {
-            max_distance = seq_distance;
+        let mut lines = best.get_routes().iter().map(|route| route.to_string()).collect::<Vec<String>>();
+        let unlisted = best.get_count() - best.get_routes().len() as u64;
+        if unlisted > 0 {
+            lines.push(format!("... and {} more tied routes", unlisted));
         }
+        return Some(lines.join("\n"));
     }
-    return max_distance;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::fs::*;
+    use super::super::utils::graph::complete_graph_input;
 
     #[test]
     fn test_d09_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day9.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day9.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
-        assert_eq!(141, result);
+        assert_eq!(Some(141), result);
     }
 
     #[test]
     fn test_d09_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day9.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day9.txt").unwrap()).unwrap();
         let result = solve_part_2(&input);
-        assert_eq!(736, result);
+        assert_eq!(Some(736), result);
+    }
+
+    #[test]
+    fn test_d09_input_invalid() {
+        let err = generate_input("London to Dublin = 464\nLondon - Belfast = 518\n").unwrap_err();
+        assert_eq!((2, 1), (err.get_line(), err.get_column()));
+        let err = generate_input("London to Dublin = 99999999999999999999").unwrap_err();
+        assert_eq!((1, 20), (err.get_line(), err.get_column()));
+    }
+
+    const EXAMPLE_DISTANCES: &str = "London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141";
+
+    #[test]
+    fn test_d09_p1_examples() {
+        let examples = [(EXAMPLE_DISTANCES, 605), ("London to Dublin = 464", 464)];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(Some(*expected), solve_part_1(&input), "input: {}", raw_input);
+        }
+    }
+
+    #[test]
+    fn test_d09_p2_examples() {
+        let examples = [(EXAMPLE_DISTANCES, 982), ("London to Dublin = 464", 464)];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(Some(*expected), solve_part_2(&input), "input: {}", raw_input);
+        }
+    }
+
+    #[test]
+    fn test_d09_algorithms_agree() {
+        let raw_inputs = [read_to_string("./input/2015/day9.txt").unwrap(), EXAMPLE_DISTANCES.to_string()];
+        for raw_input in raw_inputs.iter() {
+            let input = generate_input(raw_input).unwrap();
+            for objective in [Objective::Shortest, Objective::Longest].iter() {
+                assert_eq!(
+                    find_route_distance(&input, *objective, RouteAlgorithm::BruteForce),
+                    find_route_distance(&input, *objective, RouteAlgorithm::HeldKarp),
+                    "objective: {:?}",
+                    objective
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_d09_many_cities() {
+        // Cities spaced out along a line, so the shortest route walks along it from one end
+        let input = complete_graph_input(16, |i, j| (j - i) as u64);
+        assert_eq!(Some(15), solve_part_1(&input));
+    }
+
+    #[test]
+    fn test_d09_too_many_cities() {
+        let input = complete_graph_input(25, |i, j| (j - i) as u64);
+        assert_eq!(None, solve_part_1(&input));
+        assert_eq!(Answer::from(TOO_MANY_CITIES), Day09::part1(&input));
+        assert_eq!(Answer::from(TOO_MANY_CITIES), Day09::part2(&input));
+        assert!(Day09::explain(1, &input).unwrap().contains("too many cities"));
+    }
+
+    #[test]
+    fn test_d09_best_routes() {
+        let input = generate_input(EXAMPLE_DISTANCES).unwrap();
+        let routes = find_best_routes(&input, Objective::Shortest, RouteAlgorithm::HeldKarp, 10);
+        let text = routes.get_routes().iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        let expected = vec!["London -> Dublin -> Belfast = 605 (464 + 141)", "Belfast -> Dublin -> London = 605 (141 + 464)"];
+        assert_eq!(expected, text);
+        let best = find_best_routes(&input, Objective::Longest, RouteAlgorithm::BruteForce, 10);
+        let longest = best.get_routes();
+        assert_eq!((2, 2), (longest.len(), best.get_count()));
+        assert_eq!(&vec![String::from("Dublin"), String::from("London"), String::from("Belfast")], longest[0].get_cities());
+        assert_eq!((&vec![464, 518], 982), (longest[0].get_leg_distances(), longest[0].get_total_distance()));
+        // Ties between different orderings are all reported
+        let square = generate_input("A to B = 1\nB to C = 1\nC to D = 1\nD to A = 1\nA to C = 5\nB to D = 5").unwrap();
+        assert_eq!(8, find_best_routes(&square, Objective::Shortest, RouteAlgorithm::HeldKarp, 10).get_routes().len());
+        // Only the first few ties are listed, with the rest counted
+        let best = find_best_routes(&square, Objective::Shortest, RouteAlgorithm::HeldKarp, 3);
+        assert_eq!((3, 8), (best.get_routes().len(), best.get_count()));
+    }
+
+    #[test]
+    fn test_d09_explain_many_ties() {
+        // Every route through twelve cities an equal distance apart is tied, far too many to list
+        let input = complete_graph_input(12, |_, _| 1);
+        let explanation = Day09::explain(1, &input).unwrap();
+        assert_eq!(MAX_EXPLAINED_ROUTES + 1, explanation.lines().count());
+        assert_eq!(Some("... and 479001590 more tied routes"), explanation.lines().last());
+    }
+
+    #[test]
+    fn test_d09_incomplete_and_directed() {
+        // Missing legs rule out some orderings
+        let input = generate_input("A to B = 1\nB to C = 2\nC to D = 4").unwrap();
+        assert_eq!((Some(7), Some(7)), (solve_part_1(&input), solve_part_2(&input)));
+        // One-way legs can only be travelled in the given direction
+        let input = generate_input("A -> B = 1\nB -> C = 2\nC -> A = 10").unwrap();
+        assert_eq!((Some(3), Some(12)), (solve_part_1(&input), solve_part_2(&input)));
+        let routes = find_best_routes(&input, Objective::Shortest, RouteAlgorithm::BruteForce, 10);
+        assert_eq!(vec!["A -> B -> C = 3 (1 + 2)"], routes.get_routes().iter().map(|x| x.to_string()).collect::<Vec<String>>());
+        // Both algorithms agree on graphs with missing and one-way legs
+        for objective in [Objective::Shortest, Objective::Longest].iter() {
+            assert_eq!(
+                find_route_distance(&input, *objective, RouteAlgorithm::BruteForce),
+                find_route_distance(&input, *objective, RouteAlgorithm::HeldKarp)
+            );
+        }
+    }
+
+    #[test]
+    fn test_d09_no_route() {
+        for raw_input in ["A to B = 1\nC to D = 1", "A -> B = 1\nC -> B = 1"].iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(None, solve_part_1(&input), "input: {}", raw_input);
+            assert_eq!(None, solve_part_2(&input), "input: {}", raw_input);
+            assert_eq!(Answer::from(NO_ROUTE), Day09::part1(&input), "input: {}", raw_input);
+            assert_eq!(Some(String::from("no route visits every city")), Day09::explain(2, &input));
+        }
+        let err = generate_input("A => B = 1").unwrap_err();
+        assert_eq!((1, 1), (err.get_line(), err.get_column()));
+        let err = generate_input("A -> B = 99999999999999999999").unwrap_err();
+        assert_eq!((1, 10), (err.get_line(), err.get_column()));
+    }
+
+    #[test]
+    fn test_d09_route_plans() {
+        let input = generate_input(EXAMPLE_DISTANCES).unwrap();
+        let plan_distance = |plan: RoutePlan| {
+            let held_karp = plan_route_distance(&input, Objective::Shortest, &plan, RouteAlgorithm::HeldKarp);
+            let brute_force = plan_route_distance(&input, Objective::Shortest, &plan, RouteAlgorithm::BruteForce);
+            assert_eq!(brute_force, held_karp, "plan: {:?}", plan);
+            return held_karp;
+        };
+        let city = |name: &str| Some(name.to_string());
+        assert_eq!(Ok(Some(605)), plan_distance(RoutePlan::default()));
+        assert_eq!(Ok(Some(1123)), plan_distance(RoutePlan { round_trip: true, ..RoutePlan::default() }));
+        assert_eq!(Ok(Some(659)), plan_distance(RoutePlan { start: city("London"), end: city("Dublin"), ..RoutePlan::default() }));
+        assert_eq!(Ok(Some(659)), plan_distance(RoutePlan { end: city("Dublin"), ..RoutePlan::default() }));
+        let required = Some(vec![String::from("Belfast"), String::from("London")]);
+        assert_eq!(Ok(Some(518)), plan_distance(RoutePlan { required: required, ..RoutePlan::default() }));
+        let trip = RoutePlan { round_trip: true, start: city("Belfast"), ..RoutePlan::default() };
+        let best = plan_best_routes(&input, Objective::Longest, &trip, RouteAlgorithm::HeldKarp, 10).unwrap();
+        assert_eq!("Belfast -> London -> Dublin -> Belfast = 1123 (518 + 464 + 141)", best.get_routes()[0].to_string());
+        assert_eq!((2, 2), (best.get_routes().len(), best.get_count()));
+        // Plans that cannot be followed give no route, while unknown cities are errors
+        let impossible = RoutePlan { round_trip: true, start: city("London"), end: city("Dublin"), ..RoutePlan::default() };
+        assert_eq!(Ok(None), plan_distance(impossible));
+        let err = plan_distance(RoutePlan { start: city("Paris"), ..RoutePlan::default() }).unwrap_err();
+        assert_eq!("Paris", err.get_city());
     }
 }
@@ -1,6 +1,18 @@
+use super::error::ParseError;
+use super::solution::{Answer, Solution};
+use super::utils::input::PuzzleInput;
+
 #[aoc_generator(day10)]
-fn generate_input(raw_input: &str) -> Vec<char> {
-    return raw_input.trim().chars().collect::<Vec<char>>();
+pub fn generate_input(raw_input: &str) -> Result<Vec<char>, ParseError> {
+    let input = PuzzleInput::new(raw_input);
+    let sequence = input.get_line();
+    if sequence.is_empty() {
+        return Err(ParseError::new(10, 1, 1, sequence, "a non-empty sequence of digits"));
+    }
+    if let Some(col_num) = sequence.find(|c: char| !c.is_ascii_digit()) {
+        return Err(ParseError::new(10, 1, col_num + 1, sequence, "a sequence of digits only"));
+    }
+    return Ok(sequence.chars().collect::<Vec<char>>());
 }
 
 fn apply_iteration_lookandsay(input: &Vec<char>) -> Vec<char> {
@@ -33,7 +45,7 @@ fn apply_iteration_lookandsay(input: &Vec<char>) -> Vec<char> {
 }
 
 #[aoc(day10, part1)]
-fn solve_part_1(input: &Vec<char>) -> usize {
+pub fn solve_part_1(input: &Vec<char>) -> usize {
     let mut sequence = input.clone();
     // Apply 40 iterations of look-and-say to the input sequence
     for _ in 0..40 {
@@ -44,7 +56,7 @@ fn solve_part_1(input: &Vec<char>) -> usize {
 }
 
 #[aoc(day10, part2)]
-fn solve_part_2(input: &Vec<char>) -> usize {
+pub fn solve_part_2(input: &Vec<char>) -> usize {
     let mut sequence = input.clone();
     // Apply 50 iterations of look-and-say to the input sequence
     for _ in 0..50 {
@@ -54,6 +66,26 @@ fn solve_part_2(input: &Vec<char>) -> usize {
     return sequence.len();
 }
 
+/// Solution for day 10, for use through the crate's solution registry.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = Vec<char>;
+
+    fn parse(raw_input: &str) -> Result<Vec<char>, ParseError> {
+        return generate_input(raw_input);
+    }
+
+    fn part1(input: &Vec<char>) -> Answer {
+        return solve_part_1(input).into();
+    }
+
+    fn part2(input: &Vec<char>) -> Answer {
+        return solve_part_2(input).into();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,15 +93,38 @@ mod tests {
 
     #[test]
     fn test_d10_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day10.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day10.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!(329356, result);
     }
 
     #[test]
     fn test_d10_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2015/day10.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2015/day10.txt").unwrap()).unwrap();
         let result = solve_part_2(&input);
         assert_eq!(4666278, result);
     }
+
+    #[test]
+    fn test_d10_input_invalid() {
+        let err = generate_input("3113a22113").unwrap_err();
+        assert_eq!((1, 5), (err.get_line(), err.get_column()));
+        assert!(generate_input("\n").is_err());
+    }
+
+    #[test]
+    fn test_d10_lookandsay_examples() {
+        let examples = [
+            ("1", "11"),
+            ("11", "21"),
+            ("21", "1211"),
+            ("1211", "111221"),
+            ("111221", "312211"),
+        ];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            let result = apply_iteration_lookandsay(&input).iter().collect::<String>();
+            assert_eq!(*expected, result, "input: {}", raw_input);
+        }
+    }
 }
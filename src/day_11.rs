@@ -1,14 +1,27 @@
+use super::error::ParseError;
+use super::solution::{Answer, Solution};
+use super::utils::input::PuzzleInput;
+
 #[aoc_generator(day11)]
-fn generate_input(raw_input: &str) -> String {
-    return raw_input.trim().to_string();
+pub fn generate_input(raw_input: &str) -> Result<String, ParseError> {
+    let input = PuzzleInput::new(raw_input);
+    let password = input.get_line();
+    if password.is_empty() {
+        return Err(ParseError::new(11, 1, 1, password, "a non-empty password"));
+    }
+    if let Some(col_num) = password.find(|c: char| !c.is_ascii_lowercase()) {
+        return Err(ParseError::new(11, 1, col_num + 1, password, "only lowercase letters"));
+    }
+    return Ok(password.to_string());
 }
 
 /// Increments the input string - assuming it only contains lowercase alphabetical characters.
+#[allow(dead_code)] // Used by part 1 once it is implemented
 fn increment_alphabetic_string(input: &String) -> String {
     let mut output: Vec<char> = vec![];
     let mut carry = true;
     for c in input.chars().rev() {
-        if carry == false {
+        if !carry {
             output.push(c);
             continue;
         }
@@ -26,13 +39,60 @@ fn increment_alphabetic_string(input: &String) -> String {
 }
 
 #[aoc(day11, part1)]
-fn solve_part_1(input: &String) -> String {
-    let test = increment_alphabetic_string(input);
-    println!("{}", test);
+pub fn solve_part_1(_input: &String) -> String {
     unimplemented!();
 }
 
 #[aoc(day11, part2)]
-fn solve_part_2(_input: &String) -> String {
+pub fn solve_part_2(_input: &String) -> String {
     unimplemented!();
 }
+
+/// Solution for day 11, for use through the crate's solution registry.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = String;
+
+    fn parse(raw_input: &str) -> Result<String, ParseError> {
+        return generate_input(raw_input);
+    }
+
+    fn part1(input: &String) -> Answer {
+        return solve_part_1(input).into();
+    }
+
+    fn part2(input: &String) -> Answer {
+        return solve_part_2(input).into();
+    }
+
+    fn is_implemented(_part: u32) -> bool {
+        // Neither part has been solved yet
+        return false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_d11_increment_examples() {
+        let examples = [("xx", "xy"), ("xy", "xz"), ("xz", "ya"), ("ya", "yb"), ("azzz", "baaa")];
+        for (input, expected) in examples.iter() {
+            let result = increment_alphabetic_string(&input.to_string());
+            assert_eq!(*expected, result, "input: {}", input);
+        }
+    }
+
+    #[test]
+    #[ignore = "day 11 part 1 is not implemented yet"]
+    fn test_d11_p1_examples() {
+        let examples = [("abcdefgh", "abcdffaa"), ("ghijklmn", "ghjaabcc")];
+        for (raw_input, expected) in examples.iter() {
+            let input = generate_input(raw_input).unwrap();
+            assert_eq!(*expected, solve_part_1(&input), "input: {}", raw_input);
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Describes a malformed element found while parsing the raw input for a day's puzzle. Line and
+/// column numbers are 1-based, matching how editors report positions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    day: u32,
+    line: usize,
+    column: usize,
+    text: String,
+    expected: String,
+}
+
+impl ParseError {
+    /// Creates a new parse error for the given day, position, offending text and expected shape.
+    pub fn new(day: u32, line: usize, column: usize, text: &str, expected: &str) -> Self {
+        Self {
+            day: day,
+            line: line,
+            column: column,
+            text: text.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// Gets the day of the puzzle whose input could not be parsed.
+    pub fn get_day(&self) -> u32 {
+        return self.day;
+    }
+
+    /// Gets the (1-based) line number of the offending text.
+    pub fn get_line(&self) -> usize {
+        return self.line;
+    }
+
+    /// Gets the (1-based) column number at which the offending text starts.
+    pub fn get_column(&self) -> usize {
+        return self.column;
+    }
+
+    /// Gets the offending text.
+    pub fn get_text(&self) -> &str {
+        return &self.text;
+    }
+
+    /// Gets a description of what was expected in place of the offending text.
+    pub fn get_expected(&self) -> &str {
+        return &self.expected;
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day {} input, line {}, column {}: expected {}, found {:?}",
+            self.day, self.line, self.column, self.expected, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Top-level error type for anything that can go wrong while preparing or solving a puzzle.
+#[derive(Debug)]
+pub enum AocError {
+    /// The raw puzzle input was malformed.
+    Parse(ParseError),
+    /// The raw puzzle input could not be read.
+    Io(io::Error),
+    /// There is no puzzle input at the given location.
+    MissingInput(PathBuf),
+    /// No solution is registered for the requested day.
+    UnknownDay(u32),
+    /// The requested puzzle part is not 1 or 2.
+    InvalidPart(u32),
+    /// The requested part (given by day and part) has no solution yet.
+    NotImplemented(u32, u32),
+    /// The solver panicked, with the given message, before producing an answer.
+    Panic(String),
+    /// The solver did not finish within the given time limit.
+    Timeout(Duration),
+    /// A line of the known answers file (given by line number and text) is malformed.
+    InvalidAnswerLine(usize, String),
+    /// A line of a benchmark baseline file (given by line number and text) is malformed.
+    InvalidBaselineLine(usize, String),
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Parse(err) => write!(f, "parse error: {}", err),
+            AocError::Io(err) => write!(f, "I/O error: {}", err),
+            AocError::MissingInput(path) => write!(f, "no input found at {}", path.display()),
+            AocError::UnknownDay(day) => write!(f, "no solution implemented for day {}", day),
+            AocError::InvalidPart(part) => write!(f, "invalid part {} (expected 1 or 2)", part),
+            AocError::NotImplemented(day, part) => write!(f, "day {} part {} is not implemented yet", day, part),
+            AocError::Panic(message) => write!(f, "solver panicked: {}", message),
+            AocError::Timeout(timeout) => write!(f, "solver timed out after {:?}", timeout),
+            AocError::InvalidAnswerLine(line, text) => write!(
+                f,
+                "answers file, line {}: expected \"[profile]\" or \"<day> <part> <answer>\", found {:?}",
+                line, text
+            ),
+            AocError::InvalidBaselineLine(line, text) => write!(
+                f,
+                "baseline file, line {}: expected \"<day> <stage> <median nanoseconds>\", found {:?}",
+                line, text
+            ),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Parse(err) => Some(err),
+            AocError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for AocError {
+    fn from(err: ParseError) -> Self {
+        AocError::Parse(err)
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(err: io::Error) -> Self {
+        AocError::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_display() {
+        let err = ParseError::new(2, 3, 1, "2x3", "dimensions in the form LxWxH");
+        assert_eq!(
+            "day 2 input, line 3, column 1: expected dimensions in the form LxWxH, found \"2x3\"",
+            err.to_string()
+        );
+        let aoc_err = AocError::from(err);
+        assert!(aoc_err.to_string().starts_with("parse error: day 2 input, line 3"));
+    }
+}
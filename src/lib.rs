@@ -1,7 +1,19 @@
 #[macro_use]
 extern crate aoc_runner_derive;
 
+pub mod bench;
+pub mod error;
+pub mod profile;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod solution;
 pub mod utils;
+pub mod verify;
+
+pub use error::{AocError, ParseError};
+pub use registry::{get_days, get_solver, solve};
+pub use solution::{Answer, DaySolver, Solution};
 
 // Modules for day solutions
 pub mod day_01;
@@ -0,0 +1,69 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::error::AocError;
+use super::registry::get_days;
+use super::runner::get_input_path;
+
+/// Name of the profile whose inputs are held directly in the input root directory.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Gets the directory holding the puzzle inputs for the named profile. The default profile uses
+/// the input root directory itself, while every other profile has its own subdirectory.
+pub fn get_profile_dir(input_root: &Path, profile: &str) -> PathBuf {
+    if profile == DEFAULT_PROFILE {
+        return input_root.to_path_buf();
+    }
+    return input_root.join(profile);
+}
+
+/// Finds the names of the profiles with puzzle inputs under the input root directory, in
+/// alphabetical order. A directory is only treated as a profile if it holds the input for at
+/// least one registered day.
+pub fn find_profiles(input_root: &Path) -> Result<Vec<String>, AocError> {
+    let mut profiles: Vec<String> = vec![];
+    if has_inputs(input_root) {
+        profiles.push(DEFAULT_PROFILE.to_string());
+    }
+    for entry in fs::read_dir(input_root)? {
+        let path = entry?.path();
+        if !path.is_dir() || !has_inputs(&path) {
+            continue;
+        }
+        if let Some(name) = path.file_name().and_then(|name| name.to_str()) {
+            if name != DEFAULT_PROFILE {
+                profiles.push(name.to_string());
+            }
+        }
+    }
+    profiles.sort();
+    return Ok(profiles);
+}
+
+/// Checks if the directory holds the input for any of the registered days.
+fn has_inputs(dir: &Path) -> bool {
+    return get_days().iter().any(|day| get_input_path(dir, *day).is_file());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_find_profiles() {
+        let input_root = env::temp_dir().join(format!("aoc2015_profiles_{}", std::process::id()));
+        fs::create_dir_all(input_root.join("alice")).unwrap();
+        fs::create_dir_all(input_root.join("bob")).unwrap();
+        fs::create_dir_all(input_root.join("test")).unwrap();
+        fs::write(input_root.join("day1.txt"), "()").unwrap();
+        fs::write(input_root.join("alice").join("day3.txt"), "^v").unwrap();
+        fs::write(input_root.join("bob").join("day2.txt"), "2x3x4").unwrap();
+        fs::write(input_root.join("test").join("t_day_05_01.txt"), "aaa").unwrap();
+        let profiles = find_profiles(&input_root).unwrap();
+        fs::remove_dir_all(&input_root).unwrap();
+        assert_eq!(vec!["alice", "bob", "default"], profiles);
+        assert_eq!(input_root.join("bob"), get_profile_dir(&input_root, "bob"));
+        assert_eq!(input_root, get_profile_dir(&input_root, DEFAULT_PROFILE));
+    }
+}
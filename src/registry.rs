@@ -0,0 +1,65 @@
+use super::error::AocError;
+use super::solution::{Answer, DaySolver, SolutionWrapper};
+use super::{day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_11};
+
+/// Solvers for every day with an implemented solution, in order of day.
+static SOLVERS: [&dyn DaySolver; 11] = [
+    &SolutionWrapper::<day_01::Day01>::new(),
+    &SolutionWrapper::<day_02::Day02>::new(),
+    &SolutionWrapper::<day_03::Day03>::new(),
+    &SolutionWrapper::<day_04::Day04>::new(),
+    &SolutionWrapper::<day_05::Day05>::new(),
+    &SolutionWrapper::<day_06::Day06>::new(),
+    &SolutionWrapper::<day_07::Day07>::new(),
+    &SolutionWrapper::<day_08::Day08>::new(),
+    &SolutionWrapper::<day_09::Day09>::new(),
+    &SolutionWrapper::<day_10::Day10>::new(),
+    &SolutionWrapper::<day_11::Day11>::new(),
+];
+
+/// Gets the days that have a registered solution, in ascending order.
+pub fn get_days() -> Vec<u32> {
+    return SOLVERS.iter().map(|solver| solver.get_day()).collect::<Vec<u32>>();
+}
+
+/// Gets the solver registered for the given day, if there is one.
+pub fn get_solver(day: u32) -> Option<&'static dyn DaySolver> {
+    return SOLVERS.iter().find(|solver| solver.get_day() == day).copied();
+}
+
+/// Parses the raw input for the given day and solves the requested part (1 or 2) of the puzzle.
+/// Trailing newlines are stripped from the raw input before it is parsed, as done by cargo-aoc.
+pub fn solve(day: u32, part: u32, raw_input: &str) -> Result<Answer, AocError> {
+    let solver = get_solver(day).ok_or(AocError::UnknownDay(day))?;
+    if part != 1 && part != 2 {
+        return Err(AocError::InvalidPart(part));
+    }
+    if !solver.is_implemented(part) {
+        return Err(AocError::NotImplemented(day, part));
+    }
+    let input = solver.parse(raw_input.trim_end_matches('\n'))?;
+    return Ok(solver.solve(part, input.as_ref()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_days() {
+        assert_eq!((1..=11).collect::<Vec<u32>>(), get_days());
+        assert!(get_solver(7).is_some());
+        assert!(get_solver(25).is_none());
+    }
+
+    #[test]
+    fn test_registry_solve() {
+        assert_eq!(Answer::Number(-1), solve(1, 1, "())\n").unwrap());
+        assert_eq!(Answer::Number(5), solve(1, 2, "(()))").unwrap());
+        assert_eq!(Answer::from("never reaches the basement"), solve(1, 2, "(((").unwrap());
+        assert!(matches!(solve(26, 1, ""), Err(AocError::UnknownDay(26))));
+        assert!(matches!(solve(1, 3, "()"), Err(AocError::InvalidPart(3))));
+        assert!(matches!(solve(1, 1, "(x)"), Err(AocError::Parse(_))));
+        assert!(matches!(solve(11, 1, "abcdefgh"), Err(AocError::NotImplemented(11, 1))));
+    }
+}
@@ -0,0 +1,318 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+use super::error::AocError;
+use super::runner::DayOutcome;
+use super::verify::{PartVerification, Verdict};
+
+/// Status of one part of a day's puzzle after it has been run.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// An answer was found, without being checked against a known answer.
+    Ok,
+    /// The answer matches the known answer.
+    Pass,
+    /// The answer differs from the known answer.
+    Fail,
+    /// There is no known answer or input to check against, or no solution yet for the part.
+    Missing,
+    /// No answer could be found.
+    Error,
+}
+
+impl Status {
+    /// Gets the name of the status as written in reports.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Ok => return "ok",
+            Status::Pass => return "pass",
+            Status::Fail => return "fail",
+            Status::Missing => return "missing",
+            Status::Error => return "error",
+        }
+    }
+}
+
+impl From<Verdict> for Status {
+    fn from(verdict: Verdict) -> Self {
+        match verdict {
+            Verdict::Pass => Status::Pass,
+            Verdict::Fail => Status::Fail,
+            Verdict::Missing => Status::Missing,
+            Verdict::Error => Status::Error,
+        }
+    }
+}
+
+/// Record of running one part of a day's puzzle, in the form written to reports.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReportEntry {
+    /// Profile whose input was used, if the part was run as part of a verification.
+    pub profile: Option<String>,
+    pub day: u32,
+    pub part: u32,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub error: Option<String>,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+impl ReportEntry {
+    /// Creates the report entries for each requested part of the day's run.
+    pub fn from_outcome(outcome: &DayOutcome, parts: &[u32]) -> Vec<ReportEntry> {
+        let mut entries: Vec<ReportEntry> = vec![];
+        for part in parts {
+            let mut entry = ReportEntry {
+                profile: None,
+                day: outcome.day,
+                part: *part,
+                status: Status::Error,
+                answer: None,
+                expected: None,
+                error: None,
+                parse_time: outcome.parse_time,
+                solve_time: Duration::ZERO,
+            };
+            match &outcome.parts {
+                Ok(part_outcomes) => {
+                    if let Some(part_outcome) = part_outcomes.iter().find(|x| x.part == *part) {
+                        entry.solve_time = part_outcome.solve_time;
+                        match &part_outcome.answer {
+                            Ok(answer) => {
+                                entry.status = Status::Ok;
+                                entry.answer = Some(answer.to_string());
+                            }
+                            Err(err) => {
+                                if let AocError::NotImplemented(_, _) = err {
+                                    entry.status = Status::Missing;
+                                }
+                                entry.error = Some(err.to_string());
+                            }
+                        }
+                    }
+                }
+                Err(err) => entry.error = Some(err.to_string()),
+            }
+            entries.push(entry);
+        }
+        return entries;
+    }
+
+    /// Creates the report entry for the verification of a part's answer.
+    pub fn from_verification(verification: &PartVerification) -> ReportEntry {
+        return ReportEntry {
+            profile: Some(verification.profile.clone()),
+            day: verification.day,
+            part: verification.part,
+            status: Status::from(verification.verdict),
+            answer: verification.answer.as_ref().map(|answer| answer.to_string()),
+            expected: verification.expected.clone(),
+            error: verification.error.clone(),
+            parse_time: verification.parse_time,
+            solve_time: verification.solve_time,
+        };
+    }
+}
+
+/// Formats the entries as a JSON document, holding the list of results followed by a count of
+/// the results with each status. Times are given in seconds.
+pub fn to_json(entries: &[ReportEntry]) -> String {
+    let mut json = String::from("{\n  \"results\": [");
+    for (i, entry) in entries.iter().enumerate() {
+        let separator = if i == 0 { "" } else { "," };
+        write!(
+            json,
+            "{}\n    {{\"profile\": {}, \"day\": {}, \"part\": {}, \"status\": \"{}\", \"answer\": {}, \
+             \"expected\": {}, \"error\": {}, \"parse_time\": {}, \"solve_time\": {}}}",
+            separator,
+            json_string(entry.profile.as_deref()),
+            entry.day,
+            entry.part,
+            entry.status.as_str(),
+            json_string(entry.answer.as_deref()),
+            json_string(entry.expected.as_deref()),
+            json_string(entry.error.as_deref()),
+            entry.parse_time.as_secs_f64(),
+            entry.solve_time.as_secs_f64()
+        )
+        .unwrap();
+    }
+    json.push_str("\n  ],\n  \"summary\": {");
+    let statuses = [Status::Ok, Status::Pass, Status::Fail, Status::Missing, Status::Error];
+    write!(json, "\"total\": {}", entries.len()).unwrap();
+    for status in statuses.iter() {
+        let count = entries.iter().filter(|x| x.status == *status).count();
+        write!(json, ", \"{}\": {}", status.as_str(), count).unwrap();
+    }
+    json.push_str("}\n}\n");
+    return json;
+}
+
+/// Formats the entries as a JUnit XML document, with a test suite for each day (and profile)
+/// holding a test case for each part. Missing answers are reported as skipped test cases.
+pub fn to_junit(entries: &[ReportEntry]) -> String {
+    // Group the entries into a test suite for each profile and day
+    let mut suites: BTreeMap<(Option<&str>, u32), Vec<&ReportEntry>> = BTreeMap::new();
+    for entry in entries {
+        suites.entry((entry.profile.as_deref(), entry.day)).or_default().push(entry);
+    }
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    writeln!(xml, "<testsuites name=\"aoc2015\" {}>", junit_counts(entries.iter())).unwrap();
+    for ((profile, day), suite_entries) in suites.iter() {
+        let suite_name = match profile {
+            Some(profile) => format!("{}.day{:02}", profile, day),
+            None => format!("day{:02}", day),
+        };
+        writeln!(
+            xml,
+            "  <testsuite name=\"{}\" {}>",
+            xml_escape(&suite_name),
+            junit_counts(suite_entries.iter().copied())
+        )
+        .unwrap();
+        for entry in suite_entries {
+            write!(
+                xml,
+                "    <testcase classname=\"aoc2015.{}\" name=\"part{}\" time=\"{}\"",
+                xml_escape(&suite_name),
+                entry.part,
+                (entry.parse_time + entry.solve_time).as_secs_f64()
+            )
+            .unwrap();
+            let answer = entry.answer.as_deref().unwrap_or("");
+            let error = entry.error.as_deref().unwrap_or("");
+            let body = match entry.status {
+                Status::Ok | Status::Pass => format!("<system-out>{}</system-out>", xml_escape(answer)),
+                Status::Fail => format!(
+                    "<failure message=\"expected {}, found {}\"/>",
+                    xml_escape(entry.expected.as_deref().unwrap_or("")),
+                    xml_escape(answer)
+                ),
+                Status::Missing => match &entry.error {
+                    Some(error) => format!("<skipped message=\"{}\"/>", xml_escape(error)),
+                    None => format!(
+                        "<skipped message=\"no known answer\"/><system-out>{}</system-out>",
+                        xml_escape(answer)
+                    ),
+                },
+                Status::Error => format!("<error message=\"{}\"/>", xml_escape(error)),
+            };
+            writeln!(xml, ">{}</testcase>", body).unwrap();
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    return xml;
+}
+
+/// Formats the counts and total time of the entries as JUnit test suite attributes.
+fn junit_counts<'a, I: Iterator<Item = &'a ReportEntry>>(entries: I) -> String {
+    let (mut tests, mut failures, mut errors, mut skipped) = (0, 0, 0, 0);
+    let mut time = Duration::ZERO;
+    for entry in entries {
+        tests += 1;
+        match entry.status {
+            Status::Fail => failures += 1,
+            Status::Error => errors += 1,
+            Status::Missing => skipped += 1,
+            _ => (),
+        }
+        time += entry.parse_time + entry.solve_time;
+    }
+    return format!(
+        "tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{}\"",
+        tests,
+        failures,
+        errors,
+        skipped,
+        time.as_secs_f64()
+    );
+}
+
+/// Formats the optional text as a JSON string literal, or `null` if there is no text.
+fn json_string(text: Option<&str>) -> String {
+    let text = match text {
+        Some(text) => text,
+        None => return String::from("null"),
+    };
+    let mut output = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(output, "\\u{:04x}", c as u32).unwrap(),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    return output;
+}
+
+/// Escapes the text for use in XML attribute values and element content.
+fn xml_escape(text: &str) -> String {
+    let mut output = String::new();
+    for c in text.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&apos;"),
+            c if (c as u32) < 0x20 && c != '\n' && c != '\t' => (),
+            c => output.push(c),
+        }
+    }
+    return output;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{run_day, PartOutcome, RunOptions};
+
+    /// Gives the outcome of running day 1, with part 1 solved and part 2 having panicked.
+    fn run_with_panic() -> DayOutcome {
+        let mut outcome = run_day(1, &[1], "(((", &RunOptions::default());
+        outcome.parts.as_mut().unwrap().push(PartOutcome {
+            part: 2,
+            answer: Err(AocError::Panic(String::from("test panic"))),
+            solve_time: Duration::ZERO,
+            explanation: None,
+        });
+        return outcome;
+    }
+
+    #[test]
+    fn test_report_entries() {
+        let entries = ReportEntry::from_outcome(&run_with_panic(), &[1, 2]);
+        assert_eq!(Status::Ok, entries[0].status);
+        assert_eq!(Some(String::from("3")), entries[0].answer);
+        assert_eq!(Status::Error, entries[1].status);
+        assert_eq!(Some(String::from("solver panicked: test panic")), entries[1].error);
+        let entries = ReportEntry::from_outcome(&run_day(1, &[1, 2], "(x", &RunOptions::default()), &[1, 2]);
+        assert!(entries.iter().all(|x| x.status == Status::Error));
+    }
+
+    #[test]
+    fn test_report_formats() {
+        let entries = ReportEntry::from_outcome(&run_with_panic(), &[1, 2]);
+        let json = to_json(&entries);
+        assert!(json.contains("\"day\": 1, \"part\": 1, \"status\": \"ok\", \"answer\": \"3\""));
+        assert!(json.contains("\"total\": 2, \"ok\": 1, \"pass\": 0, \"fail\": 0, \"missing\": 0, \"error\": 1"));
+        let xml = to_junit(&entries);
+        assert!(xml.contains("<testsuite name=\"day01\" tests=\"2\" failures=\"0\" errors=\"1\" skipped=\"0\""));
+        assert!(xml.contains("<error message=\"solver panicked: test panic\"/>"));
+        // Parts without a solution yet are skipped rather than reported as errors
+        let entries = ReportEntry::from_outcome(&run_day(11, &[1], "abcdefgh", &RunOptions::default()), &[1]);
+        assert!(to_json(&entries).contains("\"status\": \"missing\""));
+        assert!(to_junit(&entries).contains("<skipped message=\"day 11 part 1 is not implemented yet\"/>"));
+        assert_eq!("\"a\\\"b\\\\c\\n\"", json_string(Some("a\"b\\c\n")));
+        assert_eq!("&lt;a&gt; &amp; &quot;b&quot;", xml_escape("<a> & \"b\""));
+    }
+}
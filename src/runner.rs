@@ -0,0 +1,309 @@
+use std::any::Any;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::error::AocError;
+use super::registry::get_solver;
+use super::solution::{Answer, DaySolver};
+
+/// Outcome of solving a single part of a day's puzzle.
+pub struct PartOutcome {
+    pub part: u32,
+    pub answer: Result<Answer, AocError>,
+    pub solve_time: Duration,
+    /// Details of how the answer was reached, if requested and given by the solution.
+    pub explanation: Option<String>,
+}
+
+/// Outcome of parsing the input for a day's puzzle and solving the requested parts.
+pub struct DayOutcome {
+    pub day: u32,
+    pub parse_time: Duration,
+    /// Outcome of each requested part, or the error that stopped the input from being parsed.
+    pub parts: Result<Vec<PartOutcome>, AocError>,
+}
+
+/// Gets the location of the input for the given day within the input directory.
+pub fn get_input_path(input_dir: &Path, day: u32) -> PathBuf {
+    return input_dir.join(format!("day{}.txt", day));
+}
+
+/// Reads the raw input from the given file.
+pub fn read_input(path: &Path) -> Result<String, AocError> {
+    return Ok(fs::read_to_string(path)?);
+}
+
+/// Default size of the stack given to each solver thread, in bytes. This is well above the usual
+/// thread stack size to leave room for deeply recursive solvers.
+pub const DEFAULT_STACK_SIZE: usize = 256 * 1024 * 1024;
+
+/// Options controlling how the solvers are run.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Size of the stack given to the thread running the parser and each part, in bytes.
+    pub stack_size: usize,
+    /// Longest time to wait for the parser or a part to finish, or `None` to wait indefinitely.
+    pub timeout: Option<Duration>,
+    /// Whether to also ask each part for details of how its answer was reached.
+    pub verbose: bool,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            stack_size: DEFAULT_STACK_SIZE,
+            timeout: None,
+            verbose: false,
+        }
+    }
+}
+
+/// Parses the raw input for the given day and solves each of the requested parts.
+///
+/// The parser and each part are run on their own thread, using the stack size and timeout from
+/// the options. Panics raised while parsing or solving are caught and reported as errors for the
+/// affected part, as are timeouts, so the remaining parts are still run. A thread that times out
+/// cannot be stopped, so it is left running in the background until the process exits.
+pub fn run_day(day: u32, parts: &[u32], raw_input: &str, options: &RunOptions) -> DayOutcome {
+    match get_solver(day) {
+        Some(solver) => return run_solver(solver, parts, raw_input, options),
+        None => {
+            return DayOutcome {
+                day: day,
+                parse_time: Duration::ZERO,
+                parts: Err(AocError::UnknownDay(day)),
+            }
+        }
+    }
+}
+
+/// Parses the raw input with the solver and solves each of the requested parts, as for `run_day`.
+fn run_solver(solver: &'static dyn DaySolver, parts: &[u32], raw_input: &str, options: &RunOptions) -> DayOutcome {
+    let day = solver.get_day();
+    let mut outcome = DayOutcome {
+        day: day,
+        parse_time: Duration::ZERO,
+        parts: Ok(vec![]),
+    };
+    // Strip trailing newlines from the input, as done by cargo-aoc
+    let raw_input: Arc<str> = Arc::from(raw_input.trim_end_matches('\n'));
+    let parse_input = raw_input.clone();
+    let parsed = run_isolated(&format!("day{}-parse", day), options, move || {
+        let start = Instant::now();
+        let input = solver.parse(&parse_input);
+        return (input, start.elapsed());
+    });
+    let mut input = match parsed {
+        Ok((Ok(input), parse_time)) => {
+            outcome.parse_time = parse_time;
+            Some(input)
+        }
+        Ok((Err(err), _)) => {
+            outcome.parts = Err(AocError::Parse(err));
+            return outcome;
+        }
+        Err(err) => {
+            outcome.parts = Err(err);
+            return outcome;
+        }
+    };
+    // Solve each of the requested parts, handing the parsed input from one part to the next
+    let mut part_outcomes: Vec<PartOutcome> = vec![];
+    for part in parts {
+        let part = *part;
+        if part != 1 && part != 2 {
+            part_outcomes.push(PartOutcome {
+                part: part,
+                answer: Err(AocError::InvalidPart(part)),
+                solve_time: Duration::ZERO,
+                explanation: None,
+            });
+            continue;
+        }
+        if !solver.is_implemented(part) {
+            part_outcomes.push(PartOutcome {
+                part: part,
+                answer: Err(AocError::NotImplemented(day, part)),
+                solve_time: Duration::ZERO,
+                explanation: None,
+            });
+            continue;
+        }
+        let part_input = input.take();
+        let raw_input = raw_input.clone();
+        let verbose = options.verbose;
+        let solved = run_isolated(&format!("day{}-part{}", day, part), options, move || {
+            // The parsed input is lost if an earlier part panicked or timed out, so parse it again
+            let part_input = match part_input {
+                Some(part_input) => part_input,
+                None => solver.parse(&raw_input).expect("input failed to parse a second time"),
+            };
+            let start = Instant::now();
+            let answer = solver.solve(part, part_input.as_ref());
+            let solve_time = start.elapsed();
+            // Explanations are gathered after timing, so they do not count towards the solve time
+            let explanation = if verbose { solver.explain(part, part_input.as_ref()) } else { None };
+            return (part_input, answer, solve_time, explanation);
+        });
+        match solved {
+            Ok((part_input, answer, solve_time, explanation)) => {
+                input = Some(part_input);
+                part_outcomes.push(PartOutcome {
+                    part: part,
+                    answer: Ok(answer),
+                    solve_time: solve_time,
+                    explanation: explanation,
+                });
+            }
+            Err(err) => part_outcomes.push(PartOutcome {
+                part: part,
+                answer: Err(err),
+                solve_time: Duration::ZERO,
+                explanation: None,
+            }),
+        }
+    }
+    outcome.parts = Ok(part_outcomes);
+    return outcome;
+}
+
+/// Calls the function on a new thread with the given name and the stack size from the options,
+/// waiting at most the timeout from the options for it to finish. Panics raised by the function
+/// are converted into errors.
+fn run_isolated<T, F>(name: &str, options: &RunOptions, f: F) -> Result<T, AocError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    thread::Builder::new()
+        .name(name.to_string())
+        .stack_size(options.stack_size)
+        .spawn(move || {
+            // The receiver is gone if the caller stopped waiting, so the result can be dropped
+            let _ = sender.send(catch_panic(f));
+        })?;
+    match options.timeout {
+        Some(timeout) => match receiver.recv_timeout(timeout) {
+            Ok(result) => return result,
+            Err(RecvTimeoutError::Timeout) => return Err(AocError::Timeout(timeout)),
+            Err(RecvTimeoutError::Disconnected) => return Err(thread_exited()),
+        },
+        None => return receiver.recv().unwrap_or_else(|_| Err(thread_exited())),
+    }
+}
+
+/// Creates the error reported when a solver thread exits without sending back a result.
+fn thread_exited() -> AocError {
+    return AocError::Panic(String::from("solver thread exited without a result"));
+}
+
+/// Calls the function, converting any panic it raises into an error.
+fn catch_panic<T, F: FnOnce() -> T>(f: F) -> Result<T, AocError> {
+    return panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| AocError::Panic(get_panic_message(payload)));
+}
+
+/// Extracts the message from the payload of a caught panic.
+fn get_panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+    return String::from("unknown panic");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseError;
+    use crate::solution::{Solution, SolutionWrapper};
+
+    /// Solution used to test how the runner handles misbehaving solvers. The input is a number of
+    /// milliseconds, which part 1 sleeps for before giving it back as the answer. Part 2 always
+    /// panics.
+    struct TestSolution;
+
+    impl Solution for TestSolution {
+        const DAY: u32 = 0;
+        type Input = u64;
+
+        fn parse(raw_input: &str) -> Result<u64, ParseError> {
+            return raw_input.parse::<u64>().map_err(|_| ParseError::new(0, 1, 1, raw_input, "a number"));
+        }
+
+        fn part1(input: &u64) -> Answer {
+            thread::sleep(Duration::from_millis(*input));
+            return Answer::from(*input);
+        }
+
+        fn part2(_input: &u64) -> Answer {
+            panic!("test part 2 always panics");
+        }
+    }
+
+    static TEST_SOLVER: SolutionWrapper<TestSolution> = SolutionWrapper::new();
+
+    #[test]
+    fn test_run_day() {
+        let outcome = run_day(1, &[1, 2], "(()))(\n", &RunOptions::default());
+        let parts = outcome.parts.unwrap();
+        assert_eq!(Answer::Number(0), *parts[0].answer.as_ref().unwrap());
+        assert_eq!(Answer::Number(5), *parts[1].answer.as_ref().unwrap());
+    }
+
+    #[test]
+    fn test_run_day_errors() {
+        let options = RunOptions::default();
+        assert!(matches!(run_day(1, &[1], "(x", &options).parts, Err(AocError::Parse(_))));
+        assert!(matches!(run_day(40, &[1], "", &options).parts, Err(AocError::UnknownDay(40))));
+        let parts = run_solver(&TEST_SOLVER, &[2], "0", &options).parts.unwrap();
+        assert!(matches!(&parts[0].answer, Err(AocError::Panic(message)) if message == "test part 2 always panics"));
+        // Parts without a solution yet are reported without being run
+        let parts = run_day(11, &[1, 2], "abcdefgh", &options).parts.unwrap();
+        assert!(matches!(parts[0].answer, Err(AocError::NotImplemented(11, 1))));
+        assert!(matches!(parts[1].answer, Err(AocError::NotImplemented(11, 2))));
+    }
+
+    #[test]
+    fn test_run_day_isolation() {
+        // Part 1 is still solved after part 2 panics and loses the parsed input
+        let parts = run_solver(&TEST_SOLVER, &[2, 1, 2], "3", &RunOptions::default()).parts.unwrap();
+        assert!(matches!(parts[0].answer, Err(AocError::Panic(_))));
+        assert_eq!(Answer::Number(3), *parts[1].answer.as_ref().unwrap());
+        assert!(matches!(parts[2].answer, Err(AocError::Panic(_))));
+        // A part that sleeps for longer than the timeout is given up on, leaving it to finish
+        // sleeping in the background
+        let options = RunOptions {
+            stack_size: DEFAULT_STACK_SIZE,
+            timeout: Some(Duration::from_millis(100)),
+            ..RunOptions::default()
+        };
+        let parts = run_solver(&TEST_SOLVER, &[1], "1000", &options).parts.unwrap();
+        assert!(matches!(parts[0].answer, Err(AocError::Timeout(_))));
+        let parts = run_solver(&TEST_SOLVER, &[1], "0", &options).parts.unwrap();
+        assert_eq!(Answer::Number(0), *parts[0].answer.as_ref().unwrap());
+    }
+
+    #[test]
+    fn test_run_day_verbose() {
+        let raw_input = "London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141";
+        let quiet = run_day(9, &[1], raw_input, &RunOptions::default()).parts.unwrap();
+        assert_eq!(None, quiet[0].explanation);
+        let options = RunOptions {
+            verbose: true,
+            ..RunOptions::default()
+        };
+        let verbose = run_day(9, &[1], raw_input, &options).parts.unwrap();
+        assert!(verbose[0].explanation.as_ref().unwrap().contains("Dublin"));
+        // Solutions without details give no explanation even in verbose mode
+        let parts = run_day(1, &[1], "(()", &options).parts.unwrap();
+        assert_eq!(None, parts[0].explanation);
+    }
+}
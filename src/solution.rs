@@ -0,0 +1,162 @@
+use std::any::Any;
+use std::fmt;
+use std::marker::PhantomData;
+
+use super::error::ParseError;
+
+/// Answer to one part of a day's puzzle. Most puzzles have a numeric answer, but some (such as
+/// the password in day 11) are answered with text.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(u16, u32, u64, usize, i32, i64);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Solution to both parts of a single day's puzzle. Solutions must not write to stdout, which the
+/// runner keeps for the answers and reports.
+pub trait Solution {
+    /// Day of the puzzle that is solved.
+    const DAY: u32;
+
+    /// Puzzle input after it has been parsed from the raw input text.
+    type Input;
+
+    /// Parses the raw puzzle input.
+    fn parse(raw_input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solves part 1 of the puzzle for the parsed input.
+    fn part1(input: &Self::Input) -> Answer;
+
+    /// Solves part 2 of the puzzle for the parsed input.
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Checks whether the given part (1 or 2) has been solved yet. Parts that have not are never
+    /// run, so `part1` or `part2` may simply panic for them.
+    fn is_implemented(_part: u32) -> bool {
+        return true;
+    }
+
+    /// Describes how the answer to the given part (1 or 2) was reached, such as the route taken,
+    /// for display in the runner's verbose mode. Most solutions have nothing to add.
+    fn explain(_part: u32, _input: &Self::Input) -> Option<String> {
+        return None;
+    }
+}
+
+/// Type-erased form of a [`Solution`], allowing the solutions for different days to be held in
+/// the same registry. The parsed input is boxed so it can be reused across both parts.
+pub trait DaySolver: Send + Sync {
+    /// Gets the day of the puzzle that is solved.
+    fn get_day(&self) -> u32;
+
+    /// Parses the raw puzzle input.
+    fn parse(&self, raw_input: &str) -> Result<Box<dyn Any + Send>, ParseError>;
+
+    /// Solves the given part (1 or 2) of the puzzle for input previously returned by `parse`.
+    /// Panics if the part is invalid or the input was produced by the solver for a different day.
+    fn solve(&self, part: u32, input: &(dyn Any + Send)) -> Answer;
+
+    /// Checks whether the given part (1 or 2) has been solved yet.
+    fn is_implemented(&self, part: u32) -> bool;
+
+    /// Describes how the answer to the given part was reached, if the solution gives any details.
+    /// Panics under the same conditions as `solve`.
+    fn explain(&self, part: u32, input: &(dyn Any + Send)) -> Option<String>;
+}
+
+/// Wraps a [`Solution`] so it can be used as a [`DaySolver`].
+pub struct SolutionWrapper<S>(PhantomData<fn() -> S>);
+
+impl<S> SolutionWrapper<S> {
+    /// Creates a new wrapper for the solution.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for SolutionWrapper<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Solution> DaySolver for SolutionWrapper<S>
+where
+    S::Input: Send + 'static,
+{
+    fn get_day(&self) -> u32 {
+        return S::DAY;
+    }
+
+    fn parse(&self, raw_input: &str) -> Result<Box<dyn Any + Send>, ParseError> {
+        let input = S::parse(raw_input)?;
+        return Ok(Box::new(input));
+    }
+
+    fn solve(&self, part: u32, input: &(dyn Any + Send)) -> Answer {
+        let input = downcast_input::<S>(input);
+        match part {
+            1 => return S::part1(input),
+            2 => return S::part2(input),
+            _ => panic!("day {} has no part {}", S::DAY, part),
+        }
+    }
+
+    fn is_implemented(&self, part: u32) -> bool {
+        return S::is_implemented(part);
+    }
+
+    fn explain(&self, part: u32, input: &(dyn Any + Send)) -> Option<String> {
+        let input = downcast_input::<S>(input);
+        match part {
+            1 | 2 => return S::explain(part, input),
+            _ => panic!("day {} has no part {}", S::DAY, part),
+        }
+    }
+}
+
+/// Recovers the parsed input for the solution from its type-erased form. Panics if the input was
+/// produced by the solver for a different day.
+fn downcast_input<S: Solution>(input: &(dyn Any + Send)) -> &S::Input
+where
+    S::Input: 'static,
+{
+    return input
+        .downcast_ref::<S::Input>()
+        .unwrap_or_else(|| panic!("input given to day {} solver is of the wrong type", S::DAY));
+}
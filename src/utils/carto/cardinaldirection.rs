@@ -1,5 +1,9 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
 /// Represents one of the four cardinal directions.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum CardinalDirection {
     North,
     East,
@@ -7,7 +11,20 @@ pub enum CardinalDirection {
     West
 }
 
+/// All four cardinal directions, clockwise starting from north.
+const CARDINAL_DIRECTIONS: [CardinalDirection; 4] = [
+    CardinalDirection::North,
+    CardinalDirection::East,
+    CardinalDirection::South,
+    CardinalDirection::West,
+];
+
 impl CardinalDirection {
+    /// Gets all four cardinal directions, clockwise starting from north.
+    pub fn all() -> [CardinalDirection; 4] {
+        return CARDINAL_DIRECTIONS;
+    }
+
     /// Determines new direction resulting from single 90-degree rotation to left (CCW).
     pub fn rotate_left(&self) -> CardinalDirection {
         match self {
@@ -28,6 +45,28 @@ impl CardinalDirection {
         }
     }
 
+    /// Gets the direction facing the other way.
+    pub fn opposite(&self) -> CardinalDirection {
+        return self.turn(2);
+    }
+
+    /// Determines new direction resulting from the given number of 90-degree rotations. Positive
+    /// values turn to the right (CW) and negative values turn to the left (CCW).
+    pub fn turn(&self, quarter_turns: i64) -> CardinalDirection {
+        let index = CARDINAL_DIRECTIONS.iter().position(|x| x == self).unwrap() as i64;
+        return CARDINAL_DIRECTIONS[(index + quarter_turns.rem_euclid(4)).rem_euclid(4) as usize];
+    }
+
+    /// Determines new direction resulting from rotation by the given number of degrees, with
+    /// positive values turning to the right (CW). Returns `None` if the rotation is not a multiple
+    /// of 90 degrees.
+    pub fn turn_degrees(&self, degrees: i64) -> Option<CardinalDirection> {
+        if degrees % 90 != 0 {
+            return None;
+        }
+        return Some(self.turn(degrees / 90));
+    }
+
     /// Gets the unit vector (length 1) corresponding to the cardinal direction.
     pub fn get_unit_vector(&self) -> (i64, i64) {
         match self {
@@ -38,3 +77,94 @@ impl CardinalDirection {
         }
     }
 }
+
+impl TryFrom<char> for CardinalDirection {
+    type Error = ParseDirectionError;
+
+    /// Parses a direction from an arrow ('^', '>', 'v', '<'), a compass letter (N, E, S, W) or a
+    /// relative letter (U, R, D, L). Letters may be given in either case.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_ascii_uppercase() {
+            '^' | 'N' | 'U' => return Ok(CardinalDirection::North),
+            '>' | 'E' | 'R' => return Ok(CardinalDirection::East),
+            'V' | 'S' | 'D' => return Ok(CardinalDirection::South),
+            '<' | 'W' | 'L' => return Ok(CardinalDirection::West),
+            _ => return Err(ParseDirectionError::new(c)),
+        }
+    }
+}
+
+impl fmt::Display for CardinalDirection {
+    /// Formats the direction as its compass letter: "N", "E", "S" or "W".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let letter = match self {
+            CardinalDirection::North => "N",
+            CardinalDirection::East => "E",
+            CardinalDirection::South => "S",
+            CardinalDirection::West => "W",
+        };
+        write!(f, "{}", letter)
+    }
+}
+
+/// Error returned when a character cannot be parsed as a direction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDirectionError {
+    c: char,
+}
+
+impl ParseDirectionError {
+    /// Creates a new error for the character that could not be parsed.
+    pub fn new(c: char) -> Self {
+        Self {
+            c: c,
+        }
+    }
+
+    /// Gets the character that could not be parsed.
+    pub fn get_char(&self) -> char {
+        return self.c;
+    }
+}
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected a direction as an arrow, NESW or UDLR letter, found {:?}", self.c)
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_cardinal_direction_parse() {
+        for alphabet in ["^>v<", "NESW", "URDL", "nesw"].iter() {
+            let parsed = alphabet.chars().map(|c| CardinalDirection::try_from(c).unwrap()).collect::<Vec<_>>();
+            assert_eq!(CardinalDirection::all().to_vec(), parsed, "alphabet: {}", alphabet);
+        }
+        assert_eq!(Err(ParseDirectionError::new('x')), CardinalDirection::try_from('x'));
+        let text = CardinalDirection::all().iter().map(|x| x.to_string()).collect::<String>();
+        assert_eq!("NESW", text);
+    }
+
+    #[test]
+    fn test_cardinal_direction_turns() {
+        let north = CardinalDirection::North;
+        assert_eq!(CardinalDirection::South, north.opposite());
+        assert_eq!(CardinalDirection::West, north.turn(-1));
+        assert_eq!(CardinalDirection::East, north.turn(5));
+        assert_eq!(north.rotate_left(), north.turn(i64::MIN + 3));
+        assert_eq!(Some(CardinalDirection::West), north.turn_degrees(270));
+        assert_eq!(Some(CardinalDirection::East), north.turn_degrees(-270));
+        assert_eq!(None, north.turn_degrees(45));
+        let mut counts: HashMap<CardinalDirection, usize> = HashMap::new();
+        for dir in CardinalDirection::all().iter() {
+            *counts.entry(dir.opposite()).or_insert(0) += 1;
+        }
+        assert_eq!(4, counts.len());
+    }
+}
@@ -0,0 +1,124 @@
+use std::convert::TryFrom;
+
+use super::Point2D;
+
+/// Metric used to measure the distance between two points.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DistanceMetric {
+    /// Sum of the distances along each axis (taxicab distance).
+    Manhattan,
+    /// Largest of the distances along each axis (chessboard distance).
+    Chebyshev,
+    /// Straight-line distance. Compared using squared distances so no rounding is needed.
+    Euclidean,
+}
+
+impl DistanceMetric {
+    /// Checks if the two points are no further apart than the given distance under the metric.
+    pub fn is_within(&self, a: &Point2D, b: &Point2D, distance: u64) -> bool {
+        match self {
+            DistanceMetric::Manhattan => return a.calculate_manhattan_distance(b) <= distance,
+            DistanceMetric::Chebyshev => return a.calculate_chebyshev_distance(b) <= distance,
+            DistanceMetric::Euclidean => {
+                let max_squared = (distance as u128) * (distance as u128);
+                return a.calculate_squared_euclidean_distance(b) <= max_squared;
+            }
+        }
+    }
+}
+
+/// Iterator over the points on the straight line between two points (inclusive), from the start
+/// point to the end point, using Bresenham's line algorithm. Horizontal, vertical and diagonal
+/// lines give every point along them, while other lines give the closest point in each column or
+/// row, whichever the line steps along more often.
+#[derive(Clone, Debug)]
+pub struct LinePoints {
+    // Coordinates are widened so the error terms cannot overflow for any pair of points
+    current: Option<(i128, i128)>,
+    end: (i128, i128),
+    delta_x: i128,
+    delta_y: i128,
+    step_x: i128,
+    step_y: i128,
+    error: i128,
+}
+
+impl LinePoints {
+    /// Creates an iterator over the points on the line from the start point to the end point.
+    pub fn new(start: &Point2D, end: &Point2D) -> Self {
+        let (x0, y0) = (start.get_x() as i128, start.get_y() as i128);
+        let (x1, y1) = (end.get_x() as i128, end.get_y() as i128);
+        let delta_x = (x1 - x0).abs();
+        let delta_y = -(y1 - y0).abs();
+        Self {
+            current: Some((x0, y0)),
+            end: (x1, y1),
+            delta_x: delta_x,
+            delta_y: delta_y,
+            step_x: (x1 - x0).signum(),
+            step_y: (y1 - y0).signum(),
+            error: delta_x + delta_y,
+        }
+    }
+}
+
+impl Iterator for LinePoints {
+    type Item = Point2D;
+
+    fn next(&mut self) -> Option<Point2D> {
+        let (x, y) = self.current?;
+        // Every point on the line lies between the two end points, so fits in an i64
+        let point = Point2D::new(i64::try_from(x).unwrap(), i64::try_from(y).unwrap());
+        if (x, y) == self.end {
+            self.current = None;
+            return Some(point);
+        }
+        let mut next = (x, y);
+        let double_error = 2 * self.error;
+        if double_error >= self.delta_y {
+            self.error += self.delta_y;
+            next.0 += self.step_x;
+        }
+        if double_error <= self.delta_x {
+            self.error += self.delta_x;
+            next.1 += self.step_y;
+        }
+        self.current = Some(next);
+        return Some(point);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_line(start: (i64, i64), end: (i64, i64)) -> Vec<(i64, i64)> {
+        return LinePoints::new(&Point2D::from(start), &Point2D::from(end))
+            .map(|point| (point.get_x(), point.get_y()))
+            .collect::<Vec<(i64, i64)>>();
+    }
+
+    #[test]
+    fn test_line_points() {
+        assert_eq!(vec![(2, 3)], get_line((2, 3), (2, 3)));
+        assert_eq!(vec![(0, 0), (1, 0), (2, 0), (3, 0)], get_line((0, 0), (3, 0)));
+        assert_eq!(vec![(0, 1), (0, 0), (0, -1)], get_line((0, 1), (0, -1)));
+        assert_eq!(vec![(0, 0), (-1, 1), (-2, 2)], get_line((0, 0), (-2, 2)));
+        assert_eq!(vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)], get_line((0, 0), (4, 2)));
+        assert_eq!(vec![(0, 0), (1, 1), (1, 2), (2, 3)], get_line((0, 0), (2, 3)));
+        // Lines spanning the whole coordinate range do not overflow
+        let line = LinePoints::new(&Point2D::new(i64::MIN, i64::MIN), &Point2D::new(i64::MAX, i64::MAX));
+        assert_eq!(Some(Point2D::new(i64::MIN + 1, i64::MIN + 1)), line.clone().nth(1));
+    }
+
+    #[test]
+    fn test_distance_metrics() {
+        let (a, b) = (Point2D::new(0, 0), Point2D::new(3, -4));
+        assert!(DistanceMetric::Manhattan.is_within(&a, &b, 7));
+        assert!(!DistanceMetric::Manhattan.is_within(&a, &b, 6));
+        assert!(DistanceMetric::Chebyshev.is_within(&a, &b, 4));
+        assert!(!DistanceMetric::Chebyshev.is_within(&a, &b, 3));
+        assert!(DistanceMetric::Euclidean.is_within(&a, &b, 5));
+        assert!(!DistanceMetric::Euclidean.is_within(&a, &b, 4));
+    }
+}
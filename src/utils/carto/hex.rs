@@ -0,0 +1,202 @@
+use std::ops::{Add, AddAssign};
+
+use super::Point2D;
+
+/// Represents one of the six directions on a hexagonal grid of flat-topped hexagons, where each
+/// hexagon has neighbours directly above and below it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest
+}
+
+/// All six hex directions, clockwise starting from north.
+const HEX_DIRECTIONS: [HexDirection; 6] = [
+    HexDirection::North,
+    HexDirection::NorthEast,
+    HexDirection::SouthEast,
+    HexDirection::South,
+    HexDirection::SouthWest,
+    HexDirection::NorthWest,
+];
+
+impl HexDirection {
+    /// Parses the direction from its abbreviation: "n", "ne", "se", "s", "sw" or "nw".
+    pub fn from_string(input: &str) -> Option<HexDirection> {
+        match input.to_ascii_lowercase().as_str() {
+            "n" => return Some(HexDirection::North),
+            "ne" => return Some(HexDirection::NorthEast),
+            "se" => return Some(HexDirection::SouthEast),
+            "s" => return Some(HexDirection::South),
+            "sw" => return Some(HexDirection::SouthWest),
+            "nw" => return Some(HexDirection::NorthWest),
+            _ => return None,
+        }
+    }
+
+    /// Gets all six hex directions, clockwise starting from north.
+    pub fn all() -> [HexDirection; 6] {
+        return HEX_DIRECTIONS;
+    }
+
+    /// Determines new direction resulting from single 60-degree rotation to left (CCW).
+    pub fn rotate_left(&self) -> HexDirection {
+        return HEX_DIRECTIONS[(self.get_index() + 5) % 6];
+    }
+
+    /// Determines new direction resulting from single 60-degree rotation to right (CW).
+    pub fn rotate_right(&self) -> HexDirection {
+        return HEX_DIRECTIONS[(self.get_index() + 1) % 6];
+    }
+
+    /// Gets the change in axial coordinates (q, r) for a single step in the direction.
+    pub fn get_unit_vector(&self) -> (i64, i64) {
+        match self {
+            HexDirection::North => (0, -1),
+            HexDirection::NorthEast => (1, -1),
+            HexDirection::SouthEast => (1, 0),
+            HexDirection::South => (0, 1),
+            HexDirection::SouthWest => (-1, 1),
+            HexDirection::NorthWest => (-1, 0),
+        }
+    }
+
+    /// Gets the position of the direction going clockwise from north.
+    fn get_index(&self) -> usize {
+        return HEX_DIRECTIONS.iter().position(|x| x == self).unwrap();
+    }
+}
+
+impl From<HexDirection> for Point2D {
+    /// Gets the step taken when moving in the direction, in axial coordinates (q as x, r as y).
+    fn from(direction: HexDirection) -> Self {
+        return Point2D::from(direction.get_unit_vector());
+    }
+}
+
+/// Represents a single hexagon on a hexagonal grid of flat-topped hexagons, using axial
+/// coordinates (q, r). The third cube coordinate s is derived so that q + r + s = 0.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct HexPoint {
+    q: i64,
+    r: i64
+}
+
+impl HexPoint {
+    /// Creates a new hex point from its axial coordinates.
+    pub fn new(q: i64, r: i64) -> Self {
+        Self {
+            q: q,
+            r: r
+        }
+    }
+
+    /// Creates a new hex point from its cube coordinates, or `None` if they do not sum to zero.
+    pub fn from_cube(q: i64, r: i64, s: i64) -> Option<Self> {
+        if q as i128 + r as i128 + s as i128 != 0 {
+            return None;
+        }
+        return Some(HexPoint::new(q, r));
+    }
+
+    /// Gets the value of the q-coordinate.
+    pub fn get_q(&self) -> i64 {
+        return self.q;
+    }
+
+    /// Gets the value of the r-coordinate.
+    pub fn get_r(&self) -> i64 {
+        return self.r;
+    }
+
+    /// Gets the value of the derived cube s-coordinate.
+    pub fn get_s(&self) -> i64 {
+        return -self.q - self.r;
+    }
+
+    /// Gets the hex point one step away in the given direction.
+    pub fn get_neighbour(&self, direction: HexDirection) -> HexPoint {
+        let (delta_q, delta_r) = direction.get_unit_vector();
+        return HexPoint::new(self.q + delta_q, self.r + delta_r);
+    }
+
+    /// Gets the six neighbouring hex points, clockwise starting from north.
+    pub fn get_neighbours(&self) -> Vec<HexPoint> {
+        return HEX_DIRECTIONS.iter().map(|x| self.get_neighbour(*x)).collect::<Vec<HexPoint>>();
+    }
+
+    /// Calculates the number of steps between the current hex point and the other hex point.
+    pub fn calculate_hex_distance(&self, other: &HexPoint) -> u64 {
+        let delta_q = self.q as i128 - other.q as i128;
+        let delta_r = self.r as i128 - other.r as i128;
+        let delta_s = -delta_q - delta_r;
+        return ((delta_q.unsigned_abs() + delta_r.unsigned_abs() + delta_s.unsigned_abs()) / 2) as u64;
+    }
+}
+
+impl Add<HexDirection> for HexPoint {
+    type Output = HexPoint;
+
+    fn add(self, direction: HexDirection) -> HexPoint {
+        return self.get_neighbour(direction);
+    }
+}
+
+impl AddAssign<HexDirection> for HexPoint {
+    fn add_assign(&mut self, direction: HexDirection) {
+        *self = self.get_neighbour(direction);
+    }
+}
+
+impl From<HexPoint> for Point2D {
+    /// Converts the hex point into a point holding its axial coordinates (q as x, r as y).
+    fn from(point: HexPoint) -> Self {
+        return Point2D::new(point.q, point.r);
+    }
+}
+
+impl From<Point2D> for HexPoint {
+    /// Converts a point holding axial coordinates (q as x, r as y) into a hex point.
+    fn from(point: Point2D) -> Self {
+        return HexPoint::new(point.get_x(), point.get_y());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn walk(path: &str) -> HexPoint {
+        let mut point = HexPoint::new(0, 0);
+        for step in path.split(',') {
+            point += HexDirection::from_string(step).unwrap();
+        }
+        return point;
+    }
+
+    #[test]
+    fn test_hex_distance() {
+        let origin = HexPoint::new(0, 0);
+        assert_eq!(3, walk("ne,ne,ne").calculate_hex_distance(&origin));
+        assert_eq!(0, walk("ne,ne,sw,sw").calculate_hex_distance(&origin));
+        assert_eq!(2, walk("ne,ne,s,s").calculate_hex_distance(&origin));
+        assert_eq!(3, walk("se,sw,se,sw,sw").calculate_hex_distance(&origin));
+        assert!(origin.get_neighbours().iter().all(|x| x.calculate_hex_distance(&origin) == 1));
+    }
+
+    #[test]
+    fn test_hex_coordinates() {
+        let point = HexPoint::from_cube(2, -3, 1).unwrap();
+        assert_eq!((2, -3, 1), (point.get_q(), point.get_r(), point.get_s()));
+        assert_eq!(None, HexPoint::from_cube(1, 1, 1));
+        assert_eq!(point, HexPoint::from(Point2D::from(point)));
+        assert_eq!(HexDirection::NorthWest, HexDirection::North.rotate_left());
+        assert_eq!(HexDirection::SouthEast, HexDirection::NorthEast.rotate_right());
+        assert_eq!(Point2D::new(1, -1), Point2D::from(HexDirection::NorthEast));
+        assert_eq!(None, HexDirection::from_string("e"));
+    }
+}
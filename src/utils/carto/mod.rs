@@ -1,5 +1,19 @@
 mod cardinaldirection;
+mod distance;
+mod hex;
+mod neighbourhood;
+mod ordinaldirection;
 mod point2d;
+mod pointn;
+mod rect;
+mod walker;
 
-pub use self::cardinaldirection::CardinalDirection;
-pub use self::point2d::Point2D;
+pub use self::cardinaldirection::{CardinalDirection, ParseDirectionError};
+pub use self::distance::{DistanceMetric, LinePoints};
+pub use self::hex::{HexDirection, HexPoint};
+pub use self::neighbourhood::{Neighbourhood, Neighbours};
+pub use self::ordinaldirection::OrdinalDirection;
+pub use self::point2d::{ParsePointError, Point2D};
+pub use self::pointn::{Point3D, PointN};
+pub use self::rect::Rect;
+pub use self::walker::{ParseCommandError, Walker, WalkerCommand};
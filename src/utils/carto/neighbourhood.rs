@@ -0,0 +1,162 @@
+use super::{Point2D, Rect};
+
+/// Offsets to the von Neumann neighbours, clockwise starting from up.
+const VON_NEUMANN_OFFSETS: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to the Moore neighbours, clockwise starting from up.
+const MOORE_OFFSETS: [(i64, i64); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// Shape of the neighbourhood of points surrounding a point.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Neighbourhood {
+    /// The four points sharing an edge with the point: up, right, down and left.
+    VonNeumann,
+    /// The eight points sharing an edge or corner with the point, clockwise starting from up.
+    Moore,
+    /// Every other point in the square of the given radius centred on the point, in reading order.
+    /// A radius of 1 gives the same points as `Moore`.
+    Radius(u64),
+}
+
+impl Neighbourhood {
+    /// Gets the offset from the centre point to the neighbour with the given index, or `None` if
+    /// there are no more neighbours. Radius neighbourhoods have no fixed offsets, as they are
+    /// scanned over the area given by [`Neighbours::get_scan_area`] instead.
+    fn get_offset(&self, index: u128) -> Option<(i64, i64)> {
+        match self {
+            Neighbourhood::VonNeumann => return VON_NEUMANN_OFFSETS.get(index as usize).copied(),
+            Neighbourhood::Moore => return MOORE_OFFSETS.get(index as usize).copied(),
+            Neighbourhood::Radius(_) => return None,
+        }
+    }
+}
+
+/// Lazy iterator over the neighbours of a point. Neighbours whose coordinates would overflow are
+/// skipped, as are neighbours outside of the bounding rectangle if one has been given.
+#[derive(Clone, Debug)]
+pub struct Neighbours {
+    centre: Point2D,
+    neighbourhood: Neighbourhood,
+    bounds: Option<Rect>,
+    index: u128,
+}
+
+impl Neighbours {
+    /// Creates an iterator over the neighbours of the centre point in the given neighbourhood.
+    pub fn new(centre: Point2D, neighbourhood: Neighbourhood) -> Self {
+        Self {
+            centre: centre,
+            neighbourhood: neighbourhood,
+            bounds: None,
+            index: 0,
+        }
+    }
+
+    /// Limits the iterator to the neighbours within the bounding rectangle.
+    pub fn clip_to(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        return self;
+    }
+
+    /// Gets the area scanned for neighbours within the given radius: the square centred on the
+    /// centre point, cut down to the coordinates that fit in an i64 and to the bounding rectangle
+    /// if one has been given. Returns `None` if nothing is left to scan.
+    fn get_scan_area(&self, radius: u64) -> Option<Rect> {
+        let radius = radius as i128;
+        let (x, y) = (self.centre.get_x() as i128, self.centre.get_y() as i128);
+        let clamp = |value: i128| value.clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+        let square = Rect::new(
+            Point2D::new(clamp(x - radius), clamp(y - radius)),
+            Point2D::new(clamp(x + radius), clamp(y + radius)),
+        );
+        match self.bounds {
+            Some(bounds) => return square.intersection(&bounds),
+            None => return Some(square),
+        }
+    }
+
+    /// Gets the next neighbour within the given radius, in reading order over the scan area.
+    fn next_in_radius(&mut self, radius: u64) -> Option<Point2D> {
+        let area = self.get_scan_area(radius)?;
+        let width = area.get_width();
+        let top_left = area.get_top_left();
+        loop {
+            if self.index >= area.get_area() {
+                return None;
+            }
+            let x = top_left.get_x() as i128 + (self.index % width) as i128;
+            let y = top_left.get_y() as i128 + (self.index / width) as i128;
+            self.index += 1;
+            // The scan area only holds valid coordinates, so the casts cannot truncate
+            let point = Point2D::new(x as i64, y as i64);
+            // Step over the centre point, which is not its own neighbour
+            if point != self.centre {
+                return Some(point);
+            }
+        }
+    }
+}
+
+impl Iterator for Neighbours {
+    type Item = Point2D;
+
+    fn next(&mut self) -> Option<Point2D> {
+        if let Neighbourhood::Radius(radius) = self.neighbourhood {
+            return self.next_in_radius(radius);
+        }
+        loop {
+            let offset = self.neighbourhood.get_offset(self.index)?;
+            self.index += 1;
+            if let Some(point) = self.centre.checked_add(&Point2D::from(offset)) {
+                if self.bounds.map_or(true, |bounds| bounds.contains(&point)) {
+                    return Some(point);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbours() {
+        let centre = Point2D::new(0, 0);
+        let von_neumann = Neighbours::new(centre, Neighbourhood::VonNeumann).collect::<Vec<Point2D>>();
+        let expected = vec![Point2D::new(0, -1), Point2D::new(1, 0), Point2D::new(0, 1), Point2D::new(-1, 0)];
+        assert_eq!(expected, von_neumann);
+        assert_eq!(8, Neighbours::new(centre, Neighbourhood::Moore).count());
+        assert_eq!(24, Neighbours::new(centre, Neighbourhood::Radius(2)).count());
+        assert_eq!(0, Neighbours::new(centre, Neighbourhood::Radius(0)).count());
+        let mut moore = Neighbours::new(centre, Neighbourhood::Moore).collect::<Vec<Point2D>>();
+        let mut radius = Neighbours::new(centre, Neighbourhood::Radius(1)).collect::<Vec<Point2D>>();
+        moore.sort();
+        radius.sort();
+        assert_eq!(moore, radius);
+    }
+
+    #[test]
+    fn test_neighbours_clipped() {
+        // Corner of a 10x10 grid only has three Moore neighbours
+        let corner = Neighbours::new(Point2D::new(0, 0), Neighbourhood::Moore)
+            .clip_to(Rect::new(Point2D::new(0, 0), Point2D::new(9, 9)))
+            .collect::<Vec<Point2D>>();
+        assert_eq!(vec![Point2D::new(1, 0), Point2D::new(1, 1), Point2D::new(0, 1)], corner);
+        let edge = Neighbours::new(Point2D::new(5, 9), Neighbourhood::Radius(2))
+            .clip_to(Rect::new(Point2D::new(0, 0), Point2D::new(9, 9)));
+        assert_eq!(14, edge.count());
+        // Neighbours that would overflow are skipped rather than panicking
+        let extreme = Neighbours::new(Point2D::new(i64::MAX, i64::MIN), Neighbourhood::VonNeumann);
+        let expected = vec![Point2D::new(i64::MAX, i64::MIN + 1), Point2D::new(i64::MAX - 1, i64::MIN)];
+        assert_eq!(expected, extreme.collect::<Vec<Point2D>>());
+        assert_eq!(5, Neighbours::new(Point2D::new(i64::MAX, 0), Neighbourhood::Moore).count());
+        // Radius neighbours near the edge of the i64 range only include points that exist
+        let extreme = Neighbours::new(Point2D::new(i64::MAX - 1, 0), Neighbourhood::Radius(3));
+        assert_eq!(5 * 7 - 1, extreme.count());
+        let huge = Neighbours::new(Point2D::new(0, 0), Neighbourhood::Radius(u64::MAX))
+            .clip_to(Rect::new(Point2D::new(-1, -1), Point2D::new(1, 0)));
+        let expected = vec![Point2D::new(-1, -1), Point2D::new(0, -1), Point2D::new(1, -1), Point2D::new(-1, 0), Point2D::new(1, 0)];
+        assert_eq!(expected, huge.collect::<Vec<Point2D>>());
+    }
+}
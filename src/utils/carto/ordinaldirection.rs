@@ -0,0 +1,103 @@
+use super::{CardinalDirection, Point2D};
+
+/// Represents one of the eight compass points: the four cardinal directions and the four
+/// diagonal directions between them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrdinalDirection {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest
+}
+
+/// All eight compass points, clockwise starting from north.
+const ORDINAL_DIRECTIONS: [OrdinalDirection; 8] = [
+    OrdinalDirection::North,
+    OrdinalDirection::NorthEast,
+    OrdinalDirection::East,
+    OrdinalDirection::SouthEast,
+    OrdinalDirection::South,
+    OrdinalDirection::SouthWest,
+    OrdinalDirection::West,
+    OrdinalDirection::NorthWest,
+];
+
+impl OrdinalDirection {
+    /// Gets all eight compass points, clockwise starting from north.
+    pub fn all() -> [OrdinalDirection; 8] {
+        return ORDINAL_DIRECTIONS;
+    }
+
+    /// Determines new direction resulting from single 45-degree rotation to left (CCW).
+    pub fn rotate_left(&self) -> OrdinalDirection {
+        return ORDINAL_DIRECTIONS[(self.get_index() + 7) % 8];
+    }
+
+    /// Determines new direction resulting from single 45-degree rotation to right (CW).
+    pub fn rotate_right(&self) -> OrdinalDirection {
+        return ORDINAL_DIRECTIONS[(self.get_index() + 1) % 8];
+    }
+
+    /// Checks if the direction is one of the four diagonal directions.
+    pub fn is_diagonal(&self) -> bool {
+        return self.get_index() % 2 == 1;
+    }
+
+    /// Gets the vector for a single step in the direction. Diagonal steps move one unit along
+    /// both axes.
+    pub fn get_unit_vector(&self) -> (i64, i64) {
+        match self {
+            OrdinalDirection::North => (0, -1),
+            OrdinalDirection::NorthEast => (1, -1),
+            OrdinalDirection::East => (1, 0),
+            OrdinalDirection::SouthEast => (1, 1),
+            OrdinalDirection::South => (0, 1),
+            OrdinalDirection::SouthWest => (-1, 1),
+            OrdinalDirection::West => (-1, 0),
+            OrdinalDirection::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Gets the position of the direction going clockwise from north.
+    fn get_index(&self) -> usize {
+        return ORDINAL_DIRECTIONS.iter().position(|x| x == self).unwrap();
+    }
+}
+
+impl From<CardinalDirection> for OrdinalDirection {
+    fn from(direction: CardinalDirection) -> Self {
+        match direction {
+            CardinalDirection::North => OrdinalDirection::North,
+            CardinalDirection::East => OrdinalDirection::East,
+            CardinalDirection::South => OrdinalDirection::South,
+            CardinalDirection::West => OrdinalDirection::West,
+        }
+    }
+}
+
+impl From<OrdinalDirection> for Point2D {
+    /// Gets the step taken when moving in the direction.
+    fn from(direction: OrdinalDirection) -> Self {
+        return Point2D::from(direction.get_unit_vector());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ordinal_direction() {
+        assert_eq!(OrdinalDirection::NorthWest, OrdinalDirection::North.rotate_left());
+        assert_eq!(OrdinalDirection::North, OrdinalDirection::NorthWest.rotate_right());
+        assert_eq!(OrdinalDirection::SouthEast, OrdinalDirection::from(CardinalDirection::East).rotate_right());
+        assert_eq!(4, OrdinalDirection::all().iter().filter(|x| x.is_diagonal()).count());
+        let total = OrdinalDirection::all().iter().fold(Point2D::new(0, 0), |total, x| total + Point2D::from(*x));
+        assert_eq!(Point2D::new(0, 0), total);
+        assert_eq!(Point2D::new(-1, 1), Point2D::from(OrdinalDirection::SouthWest));
+    }
+}
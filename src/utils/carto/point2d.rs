@@ -1,3 +1,11 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use super::{CardinalDirection, DistanceMetric, LinePoints, Neighbourhood, Neighbours, PointN};
+
 /// Represents a single point in two-dimensional Euclidean space.
 #[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
 pub struct Point2D {
@@ -34,34 +42,255 @@ impl Point2D {
         self.y = y;
     }
 
-    /// Moves the point by the specified amount in the x- and y-directions.
+    /// Moves the point by the specified amount in the x- and y-directions. Panics if integer
+    /// overflow or underflow would occur.
     pub fn move_point(&mut self, delta_x: i64, delta_y: i64) {
-        self.x += delta_x;
-        self.y += delta_y;
+        *self = self.peek_point(delta_x, delta_y);
     }
 
     /// Determines the resulting location if the current location was shifted by given deltas.
+    /// Panics if integer overflow or underflow would occur.
     pub fn peek_point(&self, delta_x: i64, delta_y: i64) -> Point2D {
-        return Point2D::new(self.x + delta_x, self.y + delta_y);
+        return Point2D::from(PointN::from(*self).peek_point([delta_x, delta_y]));
     }
 
-    /// Gets the eight surrounding points from the current location. Panics if integer overflow or
-    /// underflow would occur.
+    /// Gets the eight surrounding points from the current location, clockwise starting from up.
+    /// Points whose coordinates would overflow are left out.
     pub fn get_surrounding_points(&self) -> Vec<Point2D> {
-        let mut output: Vec<Point2D> = vec![];
-        output.push(Point2D::new(self.x, self.y - 1)); // up
-        output.push(Point2D::new(self.x + 1, self.y - 1)); // diag - up right
-        output.push(Point2D::new(self.x + 1, self.y)); // right
-        output.push(Point2D::new(self.x + 1, self.y + 1)); // diag - down right
-        output.push(Point2D::new(self.x, self.y + 1)); // down
-        output.push(Point2D::new(self.x - 1, self.y + 1)); // diag - down left
-        output.push(Point2D::new(self.x - 1, self.y)); // left
-        output.push(Point2D::new(self.x - 1, self.y - 1)); // diag - up left
-        return output;
+        return self.iter_neighbours(Neighbourhood::Moore).collect::<Vec<Point2D>>();
+    }
+
+    /// Gets a lazy iterator over the points in the given neighbourhood of the current location.
+    /// Points whose coordinates would overflow are skipped, and the iterator can be limited to a
+    /// bounding rectangle using `Neighbours::clip_to`.
+    pub fn iter_neighbours(&self, neighbourhood: Neighbourhood) -> Neighbours {
+        return Neighbours::new(*self, neighbourhood);
+    }
+
+    /// Adds the other point to the current point, returning `None` if either coordinate would
+    /// overflow.
+    pub fn checked_add(&self, other: &Point2D) -> Option<Point2D> {
+        return PointN::from(*self).checked_peek_point([other.x, other.y]).map(Point2D::from);
     }
 
     /// Calculates the Manhattan distance between the current point and the other point.
     pub fn calculate_manhattan_distance(&self, other: &Point2D) -> u64 {
-        return (self.x - other.x).abs() as u64 + (self.y - other.y).abs() as u64;
+        return PointN::from(*self).calculate_manhattan_distance(&PointN::from(*other));
+    }
+
+    /// Calculates the Chebyshev distance between the current point and the other point, being
+    /// the largest of the distances along the x- and y-axes.
+    pub fn calculate_chebyshev_distance(&self, other: &Point2D) -> u64 {
+        return get_axis_distance(self.x, other.x).max(get_axis_distance(self.y, other.y));
+    }
+
+    /// Calculates the square of the Euclidean (straight-line) distance between the current point
+    /// and the other point. Saturates at `u128::MAX` for points at opposite extremes of the
+    /// coordinate range.
+    pub fn calculate_squared_euclidean_distance(&self, other: &Point2D) -> u128 {
+        let delta_x = get_axis_distance(self.x, other.x) as u128;
+        let delta_y = get_axis_distance(self.y, other.y) as u128;
+        return (delta_x * delta_x).saturating_add(delta_y * delta_y);
+    }
+
+    /// Gets an iterator over the points on the straight line from the current point to the end
+    /// point (inclusive).
+    pub fn iter_line_to(&self, end: &Point2D) -> LinePoints {
+        return LinePoints::new(self, end);
+    }
+
+    /// Gets an iterator over every point no further than the given distance from the current point
+    /// (including the current point) under the metric, in reading order. Points outside of the
+    /// coordinate range are left out.
+    pub fn iter_points_within(&self, metric: DistanceMetric, distance: u64) -> impl Iterator<Item = Point2D> {
+        let centre = *self;
+        let get_range = |coord: i64| {
+            let start = (coord as i128 - distance as i128).max(i64::MIN as i128) as i64;
+            let end = (coord as i128 + distance as i128).min(i64::MAX as i128) as i64;
+            return start..=end;
+        };
+        let x_range = get_range(self.x);
+        return get_range(self.y)
+            .flat_map(move |y| x_range.clone().map(move |x| Point2D::new(x, y)))
+            .filter(move |point| metric.is_within(&centre, point, distance));
+    }
+}
+
+/// Calculates the distance between two coordinates on the same axis, which always fits in a u64.
+pub(crate) fn get_axis_distance(a: i64, b: i64) -> u64 {
+    return (a as i128 - b as i128).unsigned_abs() as u64;
+}
+
+// The operators, ordering and text forms below defer to `PointN<2>`, so both point types share a
+// single implementation and panic on coordinate overflow in the same way.
+
+impl Add for Point2D {
+    type Output = Point2D;
+
+    fn add(self, other: Point2D) -> Point2D {
+        return Point2D::from(PointN::from(self) + PointN::from(other));
+    }
+}
+
+impl Add<CardinalDirection> for Point2D {
+    type Output = Point2D;
+
+    /// Gets the point one step away in the given direction.
+    fn add(self, direction: CardinalDirection) -> Point2D {
+        return self + Point2D::from(direction.get_unit_vector());
+    }
+}
+
+impl AddAssign for Point2D {
+    fn add_assign(&mut self, other: Point2D) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<CardinalDirection> for Point2D {
+    /// Moves the point one step in the given direction.
+    fn add_assign(&mut self, direction: CardinalDirection) {
+        *self = *self + direction;
+    }
+}
+
+impl Sub for Point2D {
+    type Output = Point2D;
+
+    fn sub(self, other: Point2D) -> Point2D {
+        return Point2D::from(PointN::from(self) - PointN::from(other));
+    }
+}
+
+impl Neg for Point2D {
+    type Output = Point2D;
+
+    fn neg(self) -> Point2D {
+        return Point2D::from(-PointN::from(self));
+    }
+}
+
+impl Mul<i64> for Point2D {
+    type Output = Point2D;
+
+    /// Scales both coordinates by the given factor. Panics if integer overflow or underflow would
+    /// occur.
+    fn mul(self, factor: i64) -> Point2D {
+        return Point2D::from(PointN::from(self) * factor);
+    }
+}
+
+impl From<(i64, i64)> for Point2D {
+    fn from((x, y): (i64, i64)) -> Self {
+        return Point2D::new(x, y);
+    }
+}
+
+impl Ord for Point2D {
+    /// Orders points in reading order: from top to bottom (by y), then left to right (by x).
+    fn cmp(&self, other: &Point2D) -> Ordering {
+        return PointN::from(*self).cmp(&PointN::from(*other));
+    }
+}
+
+impl PartialOrd for Point2D {
+    fn partial_cmp(&self, other: &Point2D) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl fmt::Display for Point2D {
+    /// Formats the point as "x,y".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return PointN::from(*self).fmt(f);
+    }
+}
+
+impl FromStr for Point2D {
+    type Err = ParsePointError;
+
+    /// Parses a point from "x,y" text, allowing whitespace around each coordinate.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        return text.parse::<PointN<2>>().map(Point2D::from);
+    }
+}
+
+/// Error returned when text cannot be parsed as a point.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsePointError {
+    text: String,
+}
+
+impl ParsePointError {
+    /// Creates a new error for the text that could not be parsed.
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+        }
+    }
+
+    /// Gets the text that could not be parsed.
+    pub fn get_text(&self) -> &str {
+        return &self.text;
+    }
+}
+
+impl fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected a point in the form \"x,y\", found {:?}", self.text)
+    }
+}
+
+impl Error for ParsePointError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point2d_operators() {
+        let mut point = Point2D::new(3, -2) + Point2D::from((1, 1));
+        assert_eq!(Point2D::new(4, -1), point);
+        assert_eq!(Point2D::new(2, -5), point - Point2D::new(2, 4));
+        assert_eq!(Point2D::new(-4, 1), -point);
+        assert_eq!(Point2D::new(12, -3), point * 3);
+        point += CardinalDirection::North;
+        assert_eq!(Point2D::new(4, -2), point);
+        assert_eq!(Point2D::new(3, -2), point + CardinalDirection::West);
+        assert_eq!(None, Point2D::new(i64::MAX, 0).checked_add(&Point2D::new(1, 0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "point coordinate overflowed")]
+    fn test_point2d_add_overflow() {
+        let _ = Point2D::new(0, i64::MIN) - Point2D::new(0, 1);
+    }
+
+    #[test]
+    fn test_point2d_distances() {
+        let (a, b) = (Point2D::new(1, 2), Point2D::new(-2, 6));
+        assert_eq!(7, a.calculate_manhattan_distance(&b));
+        assert_eq!(4, a.calculate_chebyshev_distance(&b));
+        assert_eq!(25, a.calculate_squared_euclidean_distance(&b));
+        let extreme = Point2D::new(i64::MAX, i64::MAX);
+        assert_eq!(u128::MAX, extreme.calculate_squared_euclidean_distance(&Point2D::new(i64::MIN, i64::MIN)));
+        assert_eq!(5, a.iter_line_to(&b).count());
+        assert_eq!(13, a.iter_points_within(DistanceMetric::Manhattan, 2).count());
+        assert_eq!(25, a.iter_points_within(DistanceMetric::Chebyshev, 2).count());
+        assert_eq!(13, a.iter_points_within(DistanceMetric::Euclidean, 2).count());
+        let first = a.iter_points_within(DistanceMetric::Manhattan, 1).next();
+        assert_eq!(Some(Point2D::new(1, 1)), first);
+        assert_eq!(4, extreme.iter_points_within(DistanceMetric::Chebyshev, 1).count());
+    }
+
+    #[test]
+    fn test_point2d_text_and_order() {
+        assert_eq!("-3,7", Point2D::new(-3, 7).to_string());
+        assert_eq!(Ok(Point2D::new(-3, 7)), " -3, 7".parse::<Point2D>());
+        assert!("3;7".parse::<Point2D>().is_err());
+        assert!("3,x".parse::<Point2D>().is_err());
+        let mut points = vec![Point2D::new(1, 1), Point2D::new(5, 0), Point2D::new(0, 1)];
+        points.sort();
+        assert_eq!(vec![Point2D::new(5, 0), Point2D::new(0, 1), Point2D::new(1, 1)], points);
     }
 }
@@ -0,0 +1,273 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use super::point2d::get_axis_distance;
+use super::{ParsePointError, Point2D};
+
+/// Represents a single point in Euclidean space with any number of dimensions, given by `D`.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct PointN<const D: usize> {
+    coords: [i64; D],
+}
+
+/// Represents a single point in three-dimensional Euclidean space.
+pub type Point3D = PointN<3>;
+
+impl<const D: usize> PointN<D> {
+    /// Creates a new point with the given coordinates.
+    pub fn new(coords: [i64; D]) -> Self {
+        Self {
+            coords: coords
+        }
+    }
+
+    /// Creates a new point at the origin.
+    pub fn origin() -> Self {
+        return PointN::new([0; D]);
+    }
+
+    /// Gets the coordinates of the point.
+    pub fn get_coords(&self) -> [i64; D] {
+        return self.coords;
+    }
+
+    /// Gets the value of the coordinate in the given dimension (starting from 0). Panics if the
+    /// dimension is out of range.
+    pub fn get_coord(&self, dim: usize) -> i64 {
+        return self.coords[dim];
+    }
+
+    /// Updates the value of the coordinate in the given dimension (starting from 0). Panics if the
+    /// dimension is out of range.
+    pub fn set_coord(&mut self, dim: usize, value: i64) {
+        self.coords[dim] = value;
+    }
+
+    /// Moves the point by the specified amount in each dimension.
+    pub fn move_point(&mut self, deltas: [i64; D]) {
+        *self = self.peek_point(deltas);
+    }
+
+    /// Determines the resulting location if the current location was shifted by given deltas.
+    /// Panics if integer overflow or underflow would occur.
+    pub fn peek_point(&self, deltas: [i64; D]) -> PointN<D> {
+        return self.checked_peek_point(deltas).expect("point coordinate overflowed");
+    }
+
+    /// Determines the resulting location if the current location was shifted by given deltas,
+    /// returning `None` if any coordinate would overflow.
+    pub fn checked_peek_point(&self, deltas: [i64; D]) -> Option<PointN<D>> {
+        let mut coords = self.coords;
+        for (coord, delta) in coords.iter_mut().zip(deltas.iter()) {
+            *coord = coord.checked_add(*delta)?;
+        }
+        return Some(PointN::new(coords));
+    }
+
+    /// Gets the surrounding points from the current location, being every point that differs by
+    /// at most one in each dimension. Points whose coordinates would overflow are left out, so
+    /// there are 3^D - 1 points away from the edges of the coordinate range.
+    pub fn get_surrounding_points(&self) -> Vec<PointN<D>> {
+        return self.iter_surrounding_points().collect::<Vec<PointN<D>>>();
+    }
+
+    /// Gets a lazy iterator over the surrounding points from the current location, skipping any
+    /// whose coordinates would overflow.
+    pub fn iter_surrounding_points(&self) -> impl Iterator<Item = PointN<D>> {
+        let centre = *self;
+        // Each offset is a D-digit number in base 3, with digits 0, 1 and 2 giving deltas of -1, 0
+        // and 1 respectively
+        return (0..3usize.pow(D as u32)).filter_map(move |offset| {
+            let mut deltas = [0; D];
+            let mut remainder = offset;
+            for delta in deltas.iter_mut() {
+                *delta = (remainder % 3) as i64 - 1;
+                remainder /= 3;
+            }
+            if deltas.iter().all(|delta| *delta == 0) {
+                return None;
+            }
+            return centre.checked_peek_point(deltas);
+        });
+    }
+
+    /// Calculates the Manhattan distance between the current point and the other point. Saturates
+    /// at `u64::MAX` for points at opposite extremes of the coordinate range.
+    pub fn calculate_manhattan_distance(&self, other: &PointN<D>) -> u64 {
+        return self
+            .coords
+            .iter()
+            .zip(other.coords.iter())
+            .fold(0, |total: u64, (a, b)| total.saturating_add(get_axis_distance(*a, *b)));
+    }
+}
+
+impl PointN<3> {
+    /// Gets the value of the x-coordinate.
+    pub fn get_x(&self) -> i64 {
+        return self.coords[0];
+    }
+
+    /// Gets the value of the y-coordinate.
+    pub fn get_y(&self) -> i64 {
+        return self.coords[1];
+    }
+
+    /// Gets the value of the z-coordinate.
+    pub fn get_z(&self) -> i64 {
+        return self.coords[2];
+    }
+}
+
+impl<const D: usize> Add for PointN<D> {
+    type Output = PointN<D>;
+
+    fn add(self, other: PointN<D>) -> PointN<D> {
+        return self.peek_point(other.coords);
+    }
+}
+
+impl<const D: usize> AddAssign for PointN<D> {
+    fn add_assign(&mut self, other: PointN<D>) {
+        self.move_point(other.coords);
+    }
+}
+
+impl<const D: usize> Sub for PointN<D> {
+    type Output = PointN<D>;
+
+    fn sub(self, other: PointN<D>) -> PointN<D> {
+        return self + -other;
+    }
+}
+
+impl<const D: usize> Neg for PointN<D> {
+    type Output = PointN<D>;
+
+    fn neg(self) -> PointN<D> {
+        return self * -1;
+    }
+}
+
+impl<const D: usize> Mul<i64> for PointN<D> {
+    type Output = PointN<D>;
+
+    /// Scales every coordinate by the given factor. Panics if integer overflow or underflow would
+    /// occur.
+    fn mul(self, factor: i64) -> PointN<D> {
+        let mut coords = self.coords;
+        for coord in coords.iter_mut() {
+            *coord = coord.checked_mul(factor).expect("point coordinate overflowed");
+        }
+        return PointN::new(coords);
+    }
+}
+
+impl<const D: usize> From<[i64; D]> for PointN<D> {
+    fn from(coords: [i64; D]) -> Self {
+        return PointN::new(coords);
+    }
+}
+
+impl From<(i64, i64, i64)> for PointN<3> {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        return PointN::new([x, y, z]);
+    }
+}
+
+impl From<Point2D> for PointN<2> {
+    fn from(point: Point2D) -> Self {
+        return PointN::new([point.get_x(), point.get_y()]);
+    }
+}
+
+impl From<PointN<2>> for Point2D {
+    fn from(point: PointN<2>) -> Self {
+        return Point2D::new(point.coords[0], point.coords[1]);
+    }
+}
+
+impl<const D: usize> Ord for PointN<D> {
+    /// Orders points in reading order, generalised to any number of dimensions: by the last
+    /// coordinate first, then each earlier coordinate in turn. For two dimensions this matches the
+    /// ordering of `Point2D`.
+    fn cmp(&self, other: &PointN<D>) -> Ordering {
+        return self.coords.iter().rev().cmp(other.coords.iter().rev());
+    }
+}
+
+impl<const D: usize> PartialOrd for PointN<D> {
+    fn partial_cmp(&self, other: &PointN<D>) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl<const D: usize> fmt::Display for PointN<D> {
+    /// Formats the point as its comma-separated coordinates, such as "x,y,z".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let coords = self.coords.iter().map(|coord| coord.to_string()).collect::<Vec<String>>();
+        write!(f, "{}", coords.join(","))
+    }
+}
+
+impl<const D: usize> FromStr for PointN<D> {
+    type Err = ParsePointError;
+
+    /// Parses a point from its comma-separated coordinates, allowing whitespace around each one.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let fields = text.split(',').collect::<Vec<&str>>();
+        if fields.len() != D {
+            return Err(ParsePointError::new(text));
+        }
+        let mut coords = [0; D];
+        for (coord, field) in coords.iter_mut().zip(fields.iter()) {
+            *coord = match field.trim().parse::<i64>() {
+                Ok(value) => value,
+                Err(_) => return Err(ParsePointError::new(text)),
+            };
+        }
+        return Ok(PointN::new(coords));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pointn_movement() {
+        let mut point = Point3D::new([1, -2, 3]);
+        point.move_point([1, 1, 1]);
+        assert_eq!((2, -1, 4), (point.get_x(), point.get_y(), point.get_z()));
+        assert_eq!(Point3D::from((3, 0, 5)), point + Point3D::new([1, 1, 1]));
+        assert_eq!(Point3D::new([-4, 2, -8]), (point - Point3D::origin()) * -2);
+        assert_eq!(7, point.calculate_manhattan_distance(&Point3D::origin()));
+        assert_eq!(26, point.get_surrounding_points().len());
+        assert_eq!(80, PointN::<4>::origin().get_surrounding_points().len());
+        let neighbours = PointN::<2>::from(Point2D::new(5, 5)).get_surrounding_points();
+        assert!(neighbours.iter().all(|x| Point2D::from(*x).calculate_manhattan_distance(&Point2D::new(5, 5)) <= 2));
+        assert_eq!(8, neighbours.len());
+        let corner = Point3D::new([i64::MAX, 0, i64::MIN]);
+        assert_eq!(2 * 3 * 2 - 1, corner.get_surrounding_points().len());
+        assert!(corner.iter_surrounding_points().all(|x| x.calculate_manhattan_distance(&corner) <= 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "point coordinate overflowed")]
+    fn test_pointn_mul_overflow() {
+        let _ = Point3D::new([1, i64::MAX / 2 + 1, 3]) * 2;
+    }
+
+    #[test]
+    fn test_pointn_text_and_order() {
+        assert_eq!("1,-2,3", Point3D::new([1, -2, 3]).to_string());
+        assert_eq!(Ok(Point3D::new([1, -2, 3])), "1, -2 ,3".parse::<Point3D>());
+        assert!("1,2".parse::<Point3D>().is_err());
+        assert!("1,2,z".parse::<Point3D>().is_err());
+        let mut points = vec![Point3D::new([0, 0, 1]), Point3D::new([1, 0, 0]), Point3D::new([0, 1, 0])];
+        points.sort();
+        assert_eq!(vec![Point3D::new([1, 0, 0]), Point3D::new([0, 1, 0]), Point3D::new([0, 0, 1])], points);
+    }
+}
@@ -0,0 +1,170 @@
+use super::Point2D;
+
+/// Axis-aligned rectangle holding every point between its top-left and bottom-right corners
+/// (inclusive), using the same orientation as `Grid`: x increases to the right and y increases
+/// downwards. A rectangle always holds at least one point.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct Rect {
+    top_left: Point2D,
+    bottom_right: Point2D,
+}
+
+impl Rect {
+    /// Creates a new rectangle from any two opposite corners. The corners are normalised, so
+    /// they can be given in either order.
+    pub fn new(corner_a: Point2D, corner_b: Point2D) -> Self {
+        Self {
+            top_left: Point2D::new(corner_a.get_x().min(corner_b.get_x()), corner_a.get_y().min(corner_b.get_y())),
+            bottom_right: Point2D::new(corner_a.get_x().max(corner_b.get_x()), corner_a.get_y().max(corner_b.get_y())),
+        }
+    }
+
+    /// Gets the top-left corner, having the smallest x- and y-coordinates.
+    pub fn get_top_left(&self) -> Point2D {
+        return self.top_left;
+    }
+
+    /// Gets the bottom-right corner, having the largest x- and y-coordinates.
+    pub fn get_bottom_right(&self) -> Point2D {
+        return self.bottom_right;
+    }
+
+    /// Gets the number of columns covered by the rectangle.
+    pub fn get_width(&self) -> u128 {
+        return (self.bottom_right.get_x() as i128 - self.top_left.get_x() as i128) as u128 + 1;
+    }
+
+    /// Gets the number of rows covered by the rectangle.
+    pub fn get_height(&self) -> u128 {
+        return (self.bottom_right.get_y() as i128 - self.top_left.get_y() as i128) as u128 + 1;
+    }
+
+    /// Gets the number of points held in the rectangle. Saturates at `u128::MAX` for a rectangle
+    /// covering the whole coordinate range.
+    pub fn get_area(&self) -> u128 {
+        return self.get_width().saturating_mul(self.get_height());
+    }
+
+    /// Checks if the point falls within the rectangle.
+    pub fn contains(&self, point: &Point2D) -> bool {
+        return point.get_x() >= self.top_left.get_x()
+            && point.get_x() <= self.bottom_right.get_x()
+            && point.get_y() >= self.top_left.get_y()
+            && point.get_y() <= self.bottom_right.get_y();
+    }
+
+    /// Checks if the other rectangle falls entirely within the rectangle.
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        return self.contains(&other.top_left) && self.contains(&other.bottom_right);
+    }
+
+    /// Gets the rectangle covered by both the rectangle and the other rectangle, or `None` if
+    /// they do not overlap.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let left = self.top_left.get_x().max(other.top_left.get_x());
+        let top = self.top_left.get_y().max(other.top_left.get_y());
+        let right = self.bottom_right.get_x().min(other.bottom_right.get_x());
+        let bottom = self.bottom_right.get_y().min(other.bottom_right.get_y());
+        if left > right || top > bottom {
+            return None;
+        }
+        return Some(Rect::new(Point2D::new(left, top), Point2D::new(right, bottom)));
+    }
+
+    /// Splits the part of the rectangle not covered by the other rectangle into at most four
+    /// disjoint rectangles. The result is empty if the other rectangle covers the whole rectangle.
+    pub fn subtract(&self, other: &Rect) -> Vec<Rect> {
+        let overlap = match self.intersection(other) {
+            Some(overlap) => overlap,
+            None => return vec![*self],
+        };
+        let mut pieces: Vec<Rect> = vec![];
+        let (left, top) = (self.top_left.get_x(), self.top_left.get_y());
+        let (right, bottom) = (self.bottom_right.get_x(), self.bottom_right.get_y());
+        let (overlap_left, overlap_top) = (overlap.top_left.get_x(), overlap.top_left.get_y());
+        let (overlap_right, overlap_bottom) = (overlap.bottom_right.get_x(), overlap.bottom_right.get_y());
+        // Full-width bands above and below the overlap
+        if overlap_top > top {
+            pieces.push(Rect::new(Point2D::new(left, top), Point2D::new(right, overlap_top - 1)));
+        }
+        if overlap_bottom < bottom {
+            pieces.push(Rect::new(Point2D::new(left, overlap_bottom + 1), Point2D::new(right, bottom)));
+        }
+        // Pieces to the left and right of the overlap, within the rows it covers
+        if overlap_left > left {
+            pieces.push(Rect::new(
+                Point2D::new(left, overlap_top),
+                Point2D::new(overlap_left - 1, overlap_bottom),
+            ));
+        }
+        if overlap_right < right {
+            pieces.push(Rect::new(
+                Point2D::new(overlap_right + 1, overlap_top),
+                Point2D::new(right, overlap_bottom),
+            ));
+        }
+        return pieces;
+    }
+
+    /// Calculates the number of points covered by at least one of the rectangles. Saturates at
+    /// `u128::MAX`.
+    pub fn calculate_union_area(rects: &[Rect]) -> u128 {
+        // Split the rectangles into disjoint pieces, so no point is counted twice
+        let mut disjoint: Vec<Rect> = vec![];
+        for rect in rects {
+            let mut pieces = vec![*rect];
+            for existing in disjoint.iter() {
+                pieces = pieces.iter().flat_map(|piece| piece.subtract(existing)).collect::<Vec<Rect>>();
+            }
+            disjoint.append(&mut pieces);
+        }
+        return disjoint.iter().fold(0, |total: u128, rect| total.saturating_add(rect.get_area()));
+    }
+
+    /// Iterates over every point held in the rectangle, in reading order.
+    pub fn iter_points(&self) -> impl Iterator<Item = Point2D> {
+        let (left, right) = (self.top_left.get_x(), self.bottom_right.get_x());
+        return (self.top_left.get_y()..=self.bottom_right.get_y())
+            .flat_map(move |y| (left..=right).map(move |x| Point2D::new(x, y)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_basics() {
+        let rect = Rect::new(Point2D::new(4, 1), Point2D::new(1, 3));
+        assert_eq!(Point2D::new(1, 1), rect.get_top_left());
+        assert_eq!(Point2D::new(4, 3), rect.get_bottom_right());
+        assert_eq!((4, 3, 12), (rect.get_width(), rect.get_height(), rect.get_area()));
+        assert!(rect.contains(&Point2D::new(4, 2)));
+        assert!(!rect.contains(&Point2D::new(0, 2)));
+        assert!(rect.contains_rect(&Rect::new(Point2D::new(2, 2), Point2D::new(3, 3))));
+        assert_eq!(12, rect.iter_points().count());
+        assert_eq!(Some(Point2D::new(2, 1)), rect.iter_points().nth(1));
+        let everything = Rect::new(Point2D::new(i64::MIN, i64::MIN), Point2D::new(i64::MAX, i64::MAX));
+        assert_eq!(1 << 64, everything.get_width());
+        assert_eq!(u128::MAX, everything.get_area());
+    }
+
+    #[test]
+    fn test_rect_set_operations() {
+        let a = Rect::new(Point2D::new(0, 0), Point2D::new(3, 3));
+        let b = Rect::new(Point2D::new(2, 2), Point2D::new(5, 5));
+        let c = Rect::new(Point2D::new(10, 10), Point2D::new(10, 10));
+        assert_eq!(Some(Rect::new(Point2D::new(2, 2), Point2D::new(3, 3))), a.intersection(&b));
+        assert_eq!(None, a.intersection(&c));
+        let pieces = a.subtract(&b);
+        assert_eq!(12, pieces.iter().map(|x| x.get_area()).sum::<u128>());
+        assert!(pieces.iter().all(|x| x.intersection(&b).is_none()));
+        assert_eq!(vec![a], a.subtract(&c));
+        assert!(b.subtract(&Rect::new(Point2D::new(0, 0), Point2D::new(9, 9))).is_empty());
+        // Hole in the middle leaves four pieces
+        let hole = Rect::new(Point2D::new(1, 1), Point2D::new(2, 2));
+        assert_eq!(4, a.subtract(&hole).len());
+        assert_eq!(29, Rect::calculate_union_area(&[a, b, c]));
+        assert_eq!(16, Rect::calculate_union_area(&[a, hole, a]));
+    }
+}
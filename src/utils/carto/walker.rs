@@ -0,0 +1,246 @@
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::{CardinalDirection, Point2D};
+
+/// Single instruction for a `Walker`, as used in its command language.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WalkerCommand {
+    /// Walk the given number of steps in the current heading ("F10").
+    Forward(u64),
+    /// Walk the given number of steps away from the current heading, without turning ("B2").
+    Back(u64),
+    /// Turn 90 degrees to the left, then walk the given number of steps ("L" or "L3").
+    Left(u64),
+    /// Turn 90 degrees to the right, then walk the given number of steps ("R" or "R3").
+    Right(u64),
+    /// Walk the given number of steps in a fixed direction, without turning ("N4", "^", "E2").
+    Strafe(CardinalDirection, u64),
+}
+
+impl FromStr for WalkerCommand {
+    type Err = ParseCommandError;
+
+    /// Parses a command from its letter followed by an optional step count. Letters may be given
+    /// in either case. A missing count means one step for F, B and strafing, and no steps for
+    /// turns.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut chars = text.trim().chars();
+        let letter = match chars.next() {
+            Some(c) => c.to_ascii_uppercase(),
+            None => return Err(ParseCommandError::new(text)),
+        };
+        let digits = chars.as_str();
+        let count = if digits.is_empty() {
+            None
+        } else {
+            match digits.parse::<u64>() {
+                Ok(count) => Some(count),
+                Err(_) => return Err(ParseCommandError::new(text)),
+            }
+        };
+        match letter {
+            'F' => return Ok(WalkerCommand::Forward(count.unwrap_or(1))),
+            'B' => return Ok(WalkerCommand::Back(count.unwrap_or(1))),
+            'L' => return Ok(WalkerCommand::Left(count.unwrap_or(0))),
+            'R' => return Ok(WalkerCommand::Right(count.unwrap_or(0))),
+            // L and R are taken by turns, so only compass letters and arrows strafe
+            'N' | 'E' | 'S' | 'W' | '^' | '>' | 'V' | '<' => {
+                let direction = CardinalDirection::try_from(letter).unwrap();
+                return Ok(WalkerCommand::Strafe(direction, count.unwrap_or(1)));
+            }
+            _ => return Err(ParseCommandError::new(text)),
+        }
+    }
+}
+
+/// Error returned when text cannot be parsed as a walker command.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseCommandError {
+    text: String,
+}
+
+impl ParseCommandError {
+    /// Creates a new error for the text that could not be parsed.
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+        }
+    }
+
+    /// Gets the text that could not be parsed.
+    pub fn get_text(&self) -> &str {
+        return &self.text;
+    }
+}
+
+impl fmt::Display for ParseCommandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected a walker command such as \"F10\", \"L\" or \"N3\", found {:?}", self.text)
+    }
+}
+
+impl Error for ParseCommandError {}
+
+/// Walker (or turtle) with a position and a heading, recording every point it steps on.
+#[derive(Clone, Debug)]
+pub struct Walker {
+    position: Point2D,
+    heading: CardinalDirection,
+    path: Vec<Point2D>,
+    visited: HashSet<Point2D>,
+    first_revisit: Option<Point2D>,
+}
+
+impl Walker {
+    /// Creates a new walker standing at the given position and facing the given heading.
+    pub fn new(position: Point2D, heading: CardinalDirection) -> Self {
+        Self {
+            position: position,
+            heading: heading,
+            path: vec![position],
+            visited: vec![position].into_iter().collect::<HashSet<Point2D>>(),
+            first_revisit: None,
+        }
+    }
+
+    /// Parses a sequence of commands separated by whitespace and/or commas, such as "F10 R L3".
+    pub fn parse_commands(text: &str) -> Result<Vec<WalkerCommand>, ParseCommandError> {
+        return text
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|token| !token.is_empty())
+            .map(|token| token.parse::<WalkerCommand>())
+            .collect::<Result<Vec<WalkerCommand>, ParseCommandError>>();
+    }
+
+    /// Gets the current position of the walker.
+    pub fn get_position(&self) -> Point2D {
+        return self.position;
+    }
+
+    /// Gets the direction the walker is currently facing.
+    pub fn get_heading(&self) -> CardinalDirection {
+        return self.heading;
+    }
+
+    /// Updates the direction the walker is facing.
+    pub fn set_heading(&mut self, heading: CardinalDirection) {
+        self.heading = heading;
+    }
+
+    /// Gets every point stepped on so far in order, starting with the starting position. Points
+    /// stepped on more than once appear once for each visit.
+    pub fn get_path(&self) -> &Vec<Point2D> {
+        return &self.path;
+    }
+
+    /// Gets the set of distinct points stepped on so far, including the starting position.
+    pub fn get_visited(&self) -> &HashSet<Point2D> {
+        return &self.visited;
+    }
+
+    /// Gets the first point the walker stepped on for a second time, if any.
+    pub fn get_first_revisit(&self) -> Option<Point2D> {
+        return self.first_revisit;
+    }
+
+    /// Walks the given number of steps in the current heading.
+    pub fn forward(&mut self, steps: u64) {
+        self.strafe(self.heading, steps);
+    }
+
+    /// Turns by the given number of 90-degree rotations, with positive values turning to the
+    /// right (CW).
+    pub fn turn(&mut self, quarter_turns: i64) {
+        self.heading = self.heading.turn(quarter_turns);
+    }
+
+    /// Turns 90 degrees to the left (CCW).
+    pub fn turn_left(&mut self) {
+        self.heading = self.heading.rotate_left();
+    }
+
+    /// Turns 90 degrees to the right (CW).
+    pub fn turn_right(&mut self) {
+        self.heading = self.heading.rotate_right();
+    }
+
+    /// Walks the given number of steps in a fixed direction, keeping the current heading.
+    pub fn strafe(&mut self, direction: CardinalDirection, steps: u64) {
+        for _ in 0..steps {
+            self.position += direction;
+            self.path.push(self.position);
+            if !self.visited.insert(self.position) && self.first_revisit.is_none() {
+                self.first_revisit = Some(self.position);
+            }
+        }
+    }
+
+    /// Carries out a single command.
+    pub fn execute(&mut self, command: &WalkerCommand) {
+        match command {
+            WalkerCommand::Forward(steps) => self.forward(*steps),
+            WalkerCommand::Back(steps) => self.strafe(self.heading.opposite(), *steps),
+            WalkerCommand::Left(steps) => {
+                self.turn_left();
+                self.forward(*steps);
+            }
+            WalkerCommand::Right(steps) => {
+                self.turn_right();
+                self.forward(*steps);
+            }
+            WalkerCommand::Strafe(direction, steps) => self.strafe(*direction, *steps),
+        }
+    }
+
+    /// Carries out each of the commands in order.
+    pub fn execute_all(&mut self, commands: &[WalkerCommand]) {
+        for command in commands {
+            self.execute(command);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_walker_commands() {
+        let commands = Walker::parse_commands("F10 R L3, b2 N ^2").unwrap();
+        let expected = vec![
+            WalkerCommand::Forward(10),
+            WalkerCommand::Right(0),
+            WalkerCommand::Left(3),
+            WalkerCommand::Back(2),
+            WalkerCommand::Strafe(CardinalDirection::North, 1),
+            WalkerCommand::Strafe(CardinalDirection::North, 2),
+        ];
+        assert_eq!(expected, commands);
+        assert_eq!(Err(ParseCommandError::new("X3")), Walker::parse_commands("F1 X3"));
+        assert!(Walker::parse_commands("F-1").is_err());
+        let mut walker = Walker::new(Point2D::new(0, 0), CardinalDirection::North);
+        walker.execute_all(&commands);
+        assert_eq!(Point2D::new(0, -14), walker.get_position());
+        assert_eq!(CardinalDirection::North, walker.get_heading());
+        assert_eq!(19, walker.get_path().len());
+    }
+
+    #[test]
+    fn test_walker_first_revisit() {
+        // AoC 2016 day 1 example: first location visited twice is 4 blocks away
+        let mut walker = Walker::new(Point2D::new(0, 0), CardinalDirection::North);
+        walker.execute_all(&Walker::parse_commands("R8, R4, R4, R8").unwrap());
+        assert_eq!(Some(Point2D::new(4, 0)), walker.get_first_revisit());
+        let mut walker = Walker::new(Point2D::new(0, 0), CardinalDirection::East);
+        walker.forward(3);
+        walker.turn(-2);
+        walker.strafe(CardinalDirection::South, 1);
+        assert_eq!(CardinalDirection::West, walker.get_heading());
+        assert_eq!(None, walker.get_first_revisit());
+        assert_eq!(5, walker.get_visited().len());
+    }
+}
@@ -0,0 +1,821 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, VecDeque};
+use itertools::Itertools;
+
+/// Index of a node within a `WeightedGraph`, assigned in the order nodes are added.
+pub type NodeId = usize;
+
+/// Whether a route search looks for the smallest or largest total weight.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Objective {
+    Shortest,
+    Longest,
+}
+
+impl Objective {
+    /// Checks if the candidate distance is strictly better than the current best distance.
+    fn is_better(&self, candidate: u64, best: u64) -> bool {
+        match self {
+            Objective::Shortest => return candidate < best,
+            Objective::Longest => return candidate > best,
+        }
+    }
+}
+
+/// Algorithm used to search for routes visiting every node.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RouteAlgorithm {
+    /// Checks every ordering of the nodes, taking O(n!) time. Only practical for about ten nodes.
+    BruteForce,
+    /// Held-Karp dynamic programming over subsets of the nodes, taking O(n²·2ⁿ) time and O(n·2ⁿ)
+    /// memory. Accepts at most twenty nodes.
+    HeldKarp,
+}
+
+impl RouteAlgorithm {
+    /// Gets the largest number of nodes a route may visit for the algorithm to search for it, or
+    /// `None` if there is no limit.
+    pub fn get_max_nodes(&self) -> Option<usize> {
+        match self {
+            RouteAlgorithm::BruteForce => return None,
+            RouteAlgorithm::HeldKarp => return Some(MAX_HELD_KARP_NODES),
+        }
+    }
+}
+
+/// Largest number of nodes the Held-Karp search accepts, keeping its table to about 170 MB, or
+/// about 340 MB with the second table used to count ties.
+const MAX_HELD_KARP_NODES: usize = 20;
+
+/// Marks subsets in the Held-Karp table that cannot be visited by any route.
+const UNREACHABLE: u64 = u64::MAX;
+
+/// Sequence of nodes visited in order, along with the total weight of the edges between them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Route {
+    nodes: Vec<NodeId>,
+    distance: u64,
+}
+
+impl Route {
+    /// Creates a new route through the given nodes with the given total distance.
+    pub fn new(nodes: Vec<NodeId>, distance: u64) -> Self {
+        Self {
+            nodes: nodes,
+            distance: distance,
+        }
+    }
+
+    /// Gets the nodes visited along the route, in order.
+    pub fn get_nodes(&self) -> &Vec<NodeId> {
+        return &self.nodes;
+    }
+
+    /// Gets the total weight of the edges travelled along the route.
+    pub fn get_distance(&self) -> u64 {
+        return self.distance;
+    }
+}
+
+/// Routes tied for the best distance, of which only a limited number may be listed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TiedRoutes {
+    routes: Vec<Route>,
+    count: u64,
+}
+
+impl TiedRoutes {
+    /// Gets the routes listed, sorted by the order of their nodes.
+    pub fn get_routes(&self) -> &Vec<Route> {
+        return &self.routes;
+    }
+
+    /// Gets the total number of routes tied for the best distance, including those not listed.
+    /// Saturates at `u64::MAX`.
+    pub fn get_count(&self) -> u64 {
+        return self.count;
+    }
+}
+
+/// Constraints on the routes found by the Hamiltonian path searches. The default is an open path
+/// with a free start and end that visits every node in the graph.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RouteConstraints {
+    /// Whether the route must return to its first node, forming a closed tour.
+    pub closed: bool,
+    /// Node the route must start from, if any.
+    pub start: Option<NodeId>,
+    /// Node the route must finish at, if any. For a closed tour this is the same as the start, so
+    /// giving a different node leaves no route.
+    pub end: Option<NodeId>,
+    /// Nodes the route must visit, or `None` to visit every node. The start and end nodes are
+    /// always visited. Other nodes are left out entirely, so each leg must be a direct edge.
+    pub required: Option<Vec<NodeId>>,
+}
+
+/// Route search problem after the constraints have been resolved against a graph.
+struct RouteProblem {
+    /// Nodes to be visited, in ascending order.
+    nodes: Vec<NodeId>,
+    /// Weight of the edge between each pair of nodes to be visited, indexed by their positions
+    /// within `nodes`.
+    weights: Vec<Option<u64>>,
+    /// Positions of the nodes the route may start from.
+    starts: Vec<usize>,
+    /// Position of the node the route must finish at, if any.
+    end: Option<usize>,
+    closed: bool,
+}
+
+impl RouteProblem {
+    /// Gets the weight of the edge between the nodes at the given positions, if present.
+    fn get_weight(&self, from: usize, to: usize) -> Option<u64> {
+        return self.weights[from * self.nodes.len() + to];
+    }
+
+    /// Creates a route through the nodes at the given positions, returning to the first node for
+    /// closed tours.
+    fn create_route(&self, order: &[usize], distance: u64) -> Route {
+        let mut nodes = order.iter().map(|index| self.nodes[*index]).collect::<Vec<NodeId>>();
+        if self.closed && order.len() > 1 {
+            nodes.push(nodes[0]);
+        }
+        return Route::new(nodes, distance);
+    }
+}
+
+/// Graph of nodes connected by directed edges with non-negative integer weights. Nodes are
+/// identified by string labels, which are interned to `NodeId` indices when first added.
+/// Undirected edges are stored as a pair of directed edges with the same weight.
+#[derive(Clone, Debug, Default)]
+pub struct WeightedGraph {
+    labels: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    // Outgoing edges for each node, ordered by target so searches are deterministic
+    edges: Vec<BTreeMap<NodeId, u64>>,
+}
+
+impl WeightedGraph {
+    /// Creates a new graph with no nodes.
+    pub fn new() -> Self {
+        return WeightedGraph::default();
+    }
+
+    /// Adds a node with the given label if not already present, returning its id.
+    pub fn add_node(&mut self, label: &str) -> NodeId {
+        if let Some(id) = self.ids.get(label) {
+            return *id;
+        }
+        let id = self.labels.len();
+        self.labels.push(label.to_string());
+        self.ids.insert(label.to_string(), id);
+        self.edges.push(BTreeMap::new());
+        return id;
+    }
+
+    /// Gets the id of the node with the given label, if present.
+    pub fn get_node_id(&self, label: &str) -> Option<NodeId> {
+        return self.ids.get(label).copied();
+    }
+
+    /// Gets the label of the node. Panics if the node is not in the graph.
+    pub fn get_label(&self, node: NodeId) -> &str {
+        return &self.labels[node];
+    }
+
+    /// Gets the number of nodes in the graph.
+    pub fn get_node_count(&self) -> usize {
+        return self.labels.len();
+    }
+
+    /// Adds a directed edge from one node to another, replacing any existing edge between them.
+    /// Panics if either node is not in the graph.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: u64) {
+        assert!(to < self.labels.len(), "edge target is not in the graph");
+        self.edges[from].insert(to, weight);
+    }
+
+    /// Adds an undirected edge between two nodes, replacing any existing edges between them.
+    /// Panics if either node is not in the graph.
+    pub fn add_undirected_edge(&mut self, a: NodeId, b: NodeId, weight: u64) {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight);
+    }
+
+    /// Gets the weight of the directed edge from one node to another, if present.
+    pub fn get_weight(&self, from: NodeId, to: NodeId) -> Option<u64> {
+        return self.edges.get(from)?.get(&to).copied();
+    }
+
+    /// Iterates over the outgoing edges of the node as (target, weight) pairs, ordered by target.
+    pub fn iter_edges_from(&self, node: NodeId) -> impl Iterator<Item = (NodeId, u64)> + '_ {
+        return self.edges[node].iter().map(|(to, weight)| (*to, *weight));
+    }
+
+    /// Calculates the total weight of the edges between consecutive nodes, or `None` if any edge is
+    /// missing or the total overflows.
+    pub fn calculate_route_distance(&self, nodes: &[NodeId]) -> Option<u64> {
+        let mut total: u64 = 0;
+        for leg in nodes.windows(2) {
+            total = total.checked_add(self.get_weight(leg[0], leg[1])?)?;
+        }
+        return Some(total);
+    }
+
+    /// Calculates the smallest total weight needed to reach each node from the source node using
+    /// Dijkstra's algorithm, or `None` for nodes that cannot be reached.
+    pub fn calculate_shortest_distances(&self, source: NodeId) -> Vec<Option<u64>> {
+        return self.run_dijkstra(source).0;
+    }
+
+    /// Finds the route with the smallest total weight from the source node to the target node, or
+    /// `None` if the target cannot be reached.
+    pub fn find_shortest_path(&self, source: NodeId, target: NodeId) -> Option<Route> {
+        let (distances, previous) = self.run_dijkstra(source);
+        let distance = distances[target]?;
+        // Walk back from the target to the source
+        let mut nodes = vec![target];
+        while let Some(node) = previous[*nodes.last().unwrap()] {
+            nodes.push(node);
+        }
+        nodes.reverse();
+        return Some(Route::new(nodes, distance));
+    }
+
+    /// Calculates the smallest number of edges needed to reach each node from the source node
+    /// using breadth-first search, ignoring weights, or `None` for nodes that cannot be reached.
+    pub fn calculate_hop_counts(&self, source: NodeId) -> Vec<Option<usize>> {
+        let mut hops: Vec<Option<usize>> = vec![None; self.labels.len()];
+        let mut queue: VecDeque<NodeId> = VecDeque::new();
+        hops[source] = Some(0);
+        queue.push_back(source);
+        while let Some(node) = queue.pop_front() {
+            let next_hops = hops[node].unwrap() + 1;
+            for (to, _) in self.iter_edges_from(node) {
+                if hops[to].is_none() {
+                    hops[to] = Some(next_hops);
+                    queue.push_back(to);
+                }
+            }
+        }
+        return hops;
+    }
+
+    /// Groups the nodes into connected components, treating every edge as undirected. Each
+    /// component is sorted by node id, and components are ordered by their smallest node id.
+    pub fn find_connected_components(&self) -> Vec<Vec<NodeId>> {
+        // Union-find over the nodes, joining the two ends of each edge
+        let mut parents = (0..self.labels.len()).collect::<Vec<NodeId>>();
+        fn find_root(parents: &mut Vec<NodeId>, node: NodeId) -> NodeId {
+            let mut root = node;
+            while parents[root] != root {
+                root = parents[root];
+            }
+            parents[node] = root;
+            return root;
+        }
+        for from in 0..self.labels.len() {
+            for (to, _) in self.iter_edges_from(from) {
+                let (root_from, root_to) = (find_root(&mut parents, from), find_root(&mut parents, to));
+                parents[root_from.max(root_to)] = root_from.min(root_to);
+            }
+        }
+        let mut components: BTreeMap<NodeId, Vec<NodeId>> = BTreeMap::new();
+        for node in 0..self.labels.len() {
+            let root = find_root(&mut parents, node);
+            components.entry(root).or_default().push(node);
+        }
+        return components.into_values().collect::<Vec<Vec<NodeId>>>();
+    }
+
+    /// Finds the best route visiting every node exactly once using the given algorithm, subject to
+    /// the constraints, or `None` if no such route exists. A closed tour also returns to its first
+    /// node, which is listed again at the end of the route. Orderings that need a missing edge are
+    /// skipped. Both algorithms find the same best distance, but may pick different routes when
+    /// there are ties. Also gives `None` if the route would visit more nodes than the algorithm
+    /// accepts (see [`RouteAlgorithm::get_max_nodes`]). Panics if the constraints name a node that
+    /// is not in the graph.
+    pub fn find_hamiltonian_path(&self, objective: Objective, constraints: &RouteConstraints, algorithm: RouteAlgorithm) -> Option<Route> {
+        return self.search_hamiltonian_paths(objective, constraints, algorithm, 1, false).routes.pop();
+    }
+
+    /// Finds every route tied for the best distance that visits every node exactly once, subject
+    /// to the constraints, sorted by the order of their nodes. The same route travelled in reverse
+    /// counts as a separate route, while closed tours without a fixed start always start from the
+    /// lowest node so rotations are not repeated. Both algorithms give the same routes, except that
+    /// no routes are given if they would visit more nodes than the algorithm accepts. The number of
+    /// tied routes can grow factorially with the number of nodes, such as when every edge has the
+    /// same weight, so [`WeightedGraph::find_tied_hamiltonian_paths`] should be used to list only
+    /// some of them.
+    pub fn find_all_hamiltonian_paths(&self, objective: Objective, constraints: &RouteConstraints, algorithm: RouteAlgorithm) -> Vec<Route> {
+        return self.find_tied_hamiltonian_paths(objective, constraints, algorithm, usize::MAX).routes;
+    }
+
+    /// Finds the routes tied for the best distance in the same way as
+    /// [`WeightedGraph::find_all_hamiltonian_paths`], but lists at most `max_routes` of them while
+    /// still counting every tie. Both algorithms count the same ties, but may list different routes
+    /// when there are more ties than are listed.
+    pub fn find_tied_hamiltonian_paths(
+        &self,
+        objective: Objective,
+        constraints: &RouteConstraints,
+        algorithm: RouteAlgorithm,
+        max_routes: usize,
+    ) -> TiedRoutes {
+        let mut tied = self.search_hamiltonian_paths(objective, constraints, algorithm, max_routes, true);
+        tied.routes.sort_by(|a, b| a.nodes.cmp(&b.nodes));
+        return tied;
+    }
+
+    /// Searches for the best routes visiting every node with the given algorithm, giving at most
+    /// `max_routes` of the tied routes. Every tie is counted if `count_ties` is set, and otherwise
+    /// the count is just the number of routes given, by both algorithms. Routes whose total
+    /// distance overflows or reaches `u64::MAX` are skipped by both algorithms.
+    fn search_hamiltonian_paths(
+        &self,
+        objective: Objective,
+        constraints: &RouteConstraints,
+        algorithm: RouteAlgorithm,
+        max_routes: usize,
+        count_ties: bool,
+    ) -> TiedRoutes {
+        let problem = match self.resolve_constraints(constraints) {
+            Some(problem) => problem,
+            None => return TiedRoutes::default(),
+        };
+        let mut tied = match algorithm {
+            RouteAlgorithm::BruteForce => search_brute_force(&problem, objective, max_routes),
+            RouteAlgorithm::HeldKarp => search_held_karp(&problem, objective, max_routes, count_ties),
+        };
+        if !count_ties {
+            tied.count = tied.routes.len() as u64;
+        }
+        return tied;
+    }
+
+    /// Works out which nodes a route must visit and where it may start and finish, or `None` if
+    /// the constraints cannot be met by any route.
+    fn resolve_constraints(&self, constraints: &RouteConstraints) -> Option<RouteProblem> {
+        let mut start = constraints.start;
+        let mut end = constraints.end;
+        // A closed tour finishes where it started
+        if constraints.closed {
+            match (start, end) {
+                (Some(start), Some(end)) if start != end => return None,
+                (None, Some(_)) => start = end,
+                _ => (),
+            }
+            end = None;
+        }
+        let mut nodes = match &constraints.required {
+            Some(required) => required.clone(),
+            None => (0..self.labels.len()).collect::<Vec<NodeId>>(),
+        };
+        nodes.extend(start.iter().chain(end.iter()));
+        nodes.sort_unstable();
+        nodes.dedup();
+        if nodes.is_empty() {
+            return None;
+        }
+        assert!(nodes.iter().all(|node| *node < self.labels.len()), "route constraints name a node not in the graph");
+        let get_position = |node: NodeId| nodes.iter().position(|x| *x == node).unwrap();
+        // Closed tours can start anywhere, so fix the first node to avoid checking rotations
+        let starts = match start {
+            Some(start) => vec![get_position(start)],
+            None if constraints.closed => vec![0],
+            None => (0..nodes.len()).collect::<Vec<usize>>(),
+        };
+        let end = end.map(get_position);
+        let weights = nodes
+            .iter()
+            .flat_map(|from| nodes.iter().map(move |to| (*from, *to)))
+            .map(|(from, to)| if from == to { None } else { self.get_weight(from, to) })
+            .collect::<Vec<Option<u64>>>();
+        return Some(RouteProblem {
+            nodes: nodes,
+            weights: weights,
+            starts: starts,
+            end: end,
+            closed: constraints.closed,
+        });
+    }
+
+    /// Runs Dijkstra's algorithm from the source node, giving the distance to each node and the
+    /// node before it on a shortest path.
+    fn run_dijkstra(&self, source: NodeId) -> (Vec<Option<u64>>, Vec<Option<NodeId>>) {
+        let mut distances: Vec<Option<u64>> = vec![None; self.labels.len()];
+        let mut previous: Vec<Option<NodeId>> = vec![None; self.labels.len()];
+        let mut queue: BinaryHeap<Reverse<(u64, NodeId)>> = BinaryHeap::new();
+        distances[source] = Some(0);
+        queue.push(Reverse((0, source)));
+        while let Some(Reverse((distance, node))) = queue.pop() {
+            // Skip stale queue entries for nodes already reached more cheaply
+            if distances[node].is_some_and(|best| distance > best) {
+                continue;
+            }
+            for (to, weight) in self.iter_edges_from(node) {
+                let next = match distance.checked_add(weight) {
+                    Some(next) => next,
+                    None => continue,
+                };
+                if distances[to].map_or(true, |best| next < best) {
+                    distances[to] = Some(next);
+                    previous[to] = Some(node);
+                    queue.push(Reverse((next, to)));
+                }
+            }
+        }
+        return (distances, previous);
+    }
+}
+
+/// Finds the best routes for the problem by checking every ordering of the nodes, always counting
+/// every tie. Without all ties, the route kept is the first found, in lexicographic order of node
+/// positions.
+fn search_brute_force(problem: &RouteProblem, objective: Objective, max_routes: usize) -> TiedRoutes {
+    let count = problem.nodes.len();
+    let mut best = TiedRoutes::default();
+    let mut best_distance: Option<u64> = None;
+    for first in problem.starts.iter() {
+        let rest = (0..count).filter(|index| index != first).collect::<Vec<usize>>();
+        for rest_order in rest.into_iter().permutations(count - 1) {
+            let mut order = vec![*first];
+            order.extend(rest_order);
+            if problem.end.is_some_and(|end| *order.last().unwrap() != end) {
+                continue;
+            }
+            if problem.closed && count > 1 {
+                order.push(*first);
+            }
+            // Add up the legs, skipping orderings that need a missing edge or overflow. A total of
+            // exactly `u64::MAX` is skipped too, as Held-Karp uses it to mark unreachable subsets.
+            let mut distance = Some(0u64);
+            for leg in order.windows(2) {
+                distance = distance.and_then(|total| total.checked_add(problem.get_weight(leg[0], leg[1])?));
+            }
+            let distance = match distance {
+                Some(distance) if distance != UNREACHABLE => distance,
+                _ => continue,
+            };
+            if problem.closed && count > 1 {
+                order.pop();
+            }
+            if best_distance.map_or(true, |best_distance| objective.is_better(distance, best_distance)) {
+                best_distance = Some(distance);
+                best = TiedRoutes::default();
+            } else if best_distance != Some(distance) {
+                continue;
+            }
+            best.count = best.count.saturating_add(1);
+            if best.routes.len() < max_routes {
+                best.routes.push(problem.create_route(&order, distance));
+            }
+        }
+    }
+    return best;
+}
+
+/// Finds the best routes for the problem using Held-Karp dynamic programming. The table holds the
+/// best distance of a route visiting each subset of nodes (as a bitmask) and ending at each node,
+/// built up from smaller subsets. Gives no routes if the problem has more than
+/// `MAX_HELD_KARP_NODES` nodes, as the table would take too much memory. When ties are to be
+/// counted, a second table counts the routes giving each best distance, so ties can be counted
+/// without walking back along every one of them.
+fn search_held_karp(problem: &RouteProblem, objective: Objective, max_routes: usize, count_ties: bool) -> TiedRoutes {
+    let count = problem.nodes.len();
+    if count > MAX_HELD_KARP_NODES {
+        return TiedRoutes::default();
+    }
+    let full = (1usize << count) - 1;
+    let mut table: Vec<u64> = vec![UNREACHABLE; (full + 1) * count];
+    let mut ties: Vec<u64> = if count_ties { vec![0; table.len()] } else { vec![] };
+    for first in problem.starts.iter() {
+        table[(1 << first) * count + first] = 0;
+        if !ties.is_empty() {
+            ties[(1 << first) * count + first] = 1;
+        }
+    }
+    // Adding a node always gives a larger mask, so smaller subsets are finished first
+    for mask in 1..=full {
+        for last in 0..count {
+            let current = table[mask * count + last];
+            if current == UNREACHABLE {
+                continue;
+            }
+            for next in 0..count {
+                if mask & (1 << next) != 0 {
+                    continue;
+                }
+                // Totals that overflow are treated as missing edges, like in the brute force
+                let distance = match problem.get_weight(last, next).and_then(|weight| current.checked_add(weight)) {
+                    Some(distance) if distance != UNREACHABLE => distance,
+                    _ => continue,
+                };
+                let cell = (mask | (1 << next)) * count + next;
+                if table[cell] == UNREACHABLE || objective.is_better(distance, table[cell]) {
+                    table[cell] = distance;
+                    if !ties.is_empty() {
+                        ties[cell] = ties[mask * count + last];
+                    }
+                } else if table[cell] == distance && !ties.is_empty() {
+                    ties[cell] = ties[cell].saturating_add(ties[mask * count + last]);
+                }
+            }
+        }
+    }
+    // Find the best final nodes, including the edge back to the start for closed tours
+    let mut best_distance: Option<u64> = None;
+    let mut best_lasts: Vec<usize> = vec![];
+    for last in 0..count {
+        let mut distance = table[full * count + last];
+        if distance == UNREACHABLE || problem.end.is_some_and(|end| last != end) {
+            continue;
+        }
+        if problem.closed && count > 1 {
+            // Closed tours always have a single start
+            let first = problem.starts[0];
+            distance = match problem.get_weight(last, first).and_then(|weight| distance.checked_add(weight)) {
+                Some(distance) if distance != UNREACHABLE => distance,
+                _ => continue,
+            };
+        }
+        if best_distance.map_or(true, |best_distance| objective.is_better(distance, best_distance)) {
+            best_distance = Some(distance);
+            best_lasts = vec![last];
+        } else if best_distance == Some(distance) {
+            best_lasts.push(last);
+        }
+    }
+    let distance = match best_distance {
+        Some(distance) => distance,
+        None => return TiedRoutes::default(),
+    };
+    // Walk back through the table from each best final node, following every previous node that
+    // leads to each step's distance until enough routes are found. Routes are built up in reverse.
+    let mut routes: Vec<Route> = vec![];
+    let mut stack = best_lasts.iter().rev().map(|last| (full, vec![*last])).collect::<Vec<(usize, Vec<usize>)>>();
+    while let Some((mask, mut order)) = stack.pop() {
+        if routes.len() >= max_routes {
+            break;
+        }
+        if mask.count_ones() == 1 {
+            order.reverse();
+            routes.push(problem.create_route(&order, distance));
+            continue;
+        }
+        let current = *order.last().unwrap();
+        let target = table[mask * count + current];
+        let previous_mask = mask & !(1 << current);
+        for previous in (0..count).rev() {
+            let previous_distance = table[previous_mask * count + previous];
+            if previous_mask & (1 << previous) == 0 || previous_distance == UNREACHABLE {
+                continue;
+            }
+            let leg = problem.get_weight(previous, current).and_then(|weight| previous_distance.checked_add(weight));
+            if leg == Some(target) {
+                let mut next_order = order.clone();
+                next_order.push(previous);
+                stack.push((previous_mask, next_order));
+            }
+        }
+    }
+    let tie_count = if ties.is_empty() {
+        routes.len() as u64
+    } else {
+        best_lasts.iter().fold(0u64, |total, last| total.saturating_add(ties[full * count + last]))
+    };
+    return TiedRoutes {
+        routes: routes,
+        count: tie_count,
+    };
+}
+
+/// Builds a complete undirected graph with the given number of nodes (up to 26), labelled
+/// "CityA", "CityB" and so on, weighting the edge between nodes `i < j` as `weight(i, j)`. Shared
+/// by tests that need a graph too big to write out by hand.
+#[cfg(test)]
+pub(crate) fn complete_graph_input(node_count: usize, weight: fn(usize, usize) -> u64) -> WeightedGraph {
+    let mut graph = WeightedGraph::new();
+    for i in 0..node_count {
+        graph.add_node(&format!("City{}", (b'A' + i as u8) as char));
+    }
+    for i in 0..node_count {
+        for j in i + 1..node_count {
+            graph.add_undirected_edge(i, j, weight(i, j));
+        }
+    }
+    return graph;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a graph from (label, label, weight) triples, using undirected edges if requested.
+    fn build_graph(edges: &[(&str, &str, u64)], undirected: bool) -> WeightedGraph {
+        let mut graph = WeightedGraph::new();
+        for (a, b, weight) in edges.iter() {
+            let (a, b) = (graph.add_node(a), graph.add_node(b));
+            if undirected {
+                graph.add_undirected_edge(a, b, *weight);
+            } else {
+                graph.add_edge(a, b, *weight);
+            }
+        }
+        return graph;
+    }
+
+    /// Gets the constraints for a closed tour visiting every node.
+    fn closed_tour() -> RouteConstraints {
+        return RouteConstraints {
+            closed: true,
+            ..RouteConstraints::default()
+        };
+    }
+
+    #[test]
+    fn test_graph_interning() {
+        let graph = build_graph(&[("A", "B", 3), ("B", "C", 4), ("A", "C", 9)], false);
+        assert_eq!(3, graph.get_node_count());
+        assert_eq!(Some(1), graph.get_node_id("B"));
+        assert_eq!(None, graph.get_node_id("D"));
+        assert_eq!("C", graph.get_label(2));
+        assert_eq!(Some(3), graph.get_weight(0, 1));
+        assert_eq!(None, graph.get_weight(1, 0));
+        assert_eq!(vec![(1, 3), (2, 9)], graph.iter_edges_from(0).collect::<Vec<(NodeId, u64)>>());
+        assert_eq!(Some(7), graph.calculate_route_distance(&[0, 1, 2]));
+        assert_eq!(None, graph.calculate_route_distance(&[2, 1]));
+    }
+
+    #[test]
+    fn test_graph_searches() {
+        let graph = build_graph(&[("A", "B", 3), ("B", "C", 4), ("A", "C", 9), ("D", "A", 1), ("E", "F", 1)], false);
+        assert_eq!(vec![Some(0), Some(3), Some(7), None, None, None], graph.calculate_shortest_distances(0));
+        assert_eq!(Some(Route::new(vec![3, 0, 1, 2], 8)), graph.find_shortest_path(3, 2));
+        assert_eq!(None, graph.find_shortest_path(2, 0));
+        assert_eq!(vec![Some(0), Some(1), Some(1), None, None, None], graph.calculate_hop_counts(0));
+        assert_eq!(vec![vec![0, 1, 2, 3], vec![4, 5]], graph.find_connected_components());
+    }
+
+    #[test]
+    fn test_graph_hamiltonian_paths() {
+        let graph = build_graph(&[("A", "B", 1), ("B", "C", 2), ("C", "D", 3), ("D", "A", 4), ("A", "C", 10), ("B", "D", 20)], true);
+        assert_eq!(Some(6), graph.find_hamiltonian_path(Objective::Shortest, &RouteConstraints::default(), RouteAlgorithm::BruteForce).map(|x| x.get_distance()));
+        assert_eq!(Some(34), graph.find_hamiltonian_path(Objective::Longest, &RouteConstraints::default(), RouteAlgorithm::BruteForce).map(|x| x.get_distance()));
+        let tour = graph.find_hamiltonian_path(Objective::Shortest, &closed_tour(), RouteAlgorithm::BruteForce).unwrap();
+        assert_eq!((&vec![0, 1, 2, 3, 0], 10), (tour.get_nodes(), tour.get_distance()));
+        // Directed edges only allow one way round
+        let one_way = build_graph(&[("A", "B", 1), ("B", "C", 1), ("C", "A", 1)], false);
+        assert_eq!(Some(Route::new(vec![0, 1, 2, 0], 3)), one_way.find_hamiltonian_path(Objective::Longest, &closed_tour(), RouteAlgorithm::BruteForce));
+        // Every route along a square is tied with its reverse and its rotations
+        let square = build_graph(&[("A", "B", 1), ("B", "C", 1), ("C", "D", 1), ("D", "A", 1)], true);
+        assert_eq!(8, square.find_all_hamiltonian_paths(Objective::Shortest, &RouteConstraints::default(), RouteAlgorithm::HeldKarp).len());
+        let tours = square.find_all_hamiltonian_paths(Objective::Shortest, &closed_tour(), RouteAlgorithm::BruteForce);
+        assert_eq!(vec![&vec![0, 1, 2, 3, 0], &vec![0, 3, 2, 1, 0]], tours.iter().map(|x| x.get_nodes()).collect::<Vec<_>>());
+        // Listing only some of the ties still counts all of them, even when there are far too many
+        // to list, as with the 12! routes through a complete graph with equal weights
+        let tied = square.find_tied_hamiltonian_paths(Objective::Shortest, &RouteConstraints::default(), RouteAlgorithm::BruteForce, 3);
+        assert_eq!((3, 8), (tied.get_routes().len(), tied.get_count()));
+        let uniform = complete_graph_input(12, |_, _| 1);
+        let tied = uniform.find_tied_hamiltonian_paths(Objective::Shortest, &RouteConstraints::default(), RouteAlgorithm::HeldKarp, 5);
+        assert_eq!((5, 479001600), (tied.get_routes().len(), tied.get_count()));
+        assert!(tied.get_routes().iter().all(|route| route.get_distance() == 11));
+        // Both algorithms agree on the count when only one route is wanted, or none
+        for max_routes in [0, 1].iter() {
+            for algorithm in [RouteAlgorithm::BruteForce, RouteAlgorithm::HeldKarp].iter() {
+                let tied = square.find_tied_hamiltonian_paths(Objective::Shortest, &RouteConstraints::default(), *algorithm, *max_routes);
+                assert_eq!((*max_routes, 8), (tied.get_routes().len(), tied.get_count()));
+            }
+        }
+        // Totals of exactly u64::MAX are skipped by both algorithms, open or closed
+        let heavy = build_graph(&[("A", "B", u64::MAX - 1), ("B", "C", 1), ("C", "A", 0)], true);
+        for algorithm in [RouteAlgorithm::BruteForce, RouteAlgorithm::HeldKarp].iter() {
+            let longest = heavy.find_hamiltonian_path(Objective::Longest, &RouteConstraints::default(), *algorithm);
+            assert_eq!(Some(u64::MAX - 1), longest.map(|x| x.get_distance()));
+            assert_eq!(None, heavy.find_hamiltonian_path(Objective::Longest, &closed_tour(), *algorithm));
+        }
+        let disconnected = build_graph(&[("A", "B", 1), ("C", "D", 1)], true);
+        assert_eq!(None, disconnected.find_hamiltonian_path(Objective::Shortest, &RouteConstraints::default(), RouteAlgorithm::HeldKarp));
+        assert_eq!(None, WeightedGraph::new().find_hamiltonian_path(Objective::Shortest, &RouteConstraints::default(), RouteAlgorithm::HeldKarp));
+    }
+
+    #[test]
+    fn test_graph_route_constraints() {
+        let graph = build_graph(&[("A", "B", 1), ("B", "C", 2), ("C", "D", 3), ("D", "A", 4), ("A", "C", 10), ("B", "D", 20)], true);
+        let find_nodes = |constraints: RouteConstraints| {
+            return graph
+                .find_hamiltonian_path(Objective::Shortest, &constraints, RouteAlgorithm::HeldKarp)
+                .map(|route| (route.nodes, route.distance));
+        };
+        let start_at_c = RouteConstraints { start: Some(2), ..RouteConstraints::default() };
+        assert_eq!(Some((vec![2, 1, 0, 3], 7)), find_nodes(start_at_c));
+        let a_to_b = RouteConstraints { start: Some(0), end: Some(1), ..RouteConstraints::default() };
+        assert_eq!(Some((vec![0, 3, 2, 1], 9)), find_nodes(a_to_b));
+        let tour_from_c = RouteConstraints { closed: true, start: Some(2), ..RouteConstraints::default() };
+        let tours = graph.find_all_hamiltonian_paths(Objective::Shortest, &tour_from_c, RouteAlgorithm::HeldKarp);
+        let expected = vec![Route::new(vec![2, 1, 0, 3, 2], 10), Route::new(vec![2, 3, 0, 1, 2], 10)];
+        assert_eq!(expected, tours);
+        // Only the required nodes are visited, along direct edges between them
+        let only_b_d = RouteConstraints { required: Some(vec![3, 1]), ..RouteConstraints::default() };
+        assert_eq!(Some(20), find_nodes(only_b_d).map(|(_, distance)| distance));
+        let via_c = RouteConstraints { start: Some(0), end: Some(1), required: Some(vec![2]), ..RouteConstraints::default() };
+        assert_eq!(Some((vec![0, 2, 1], 12)), find_nodes(via_c));
+        // Constraints that cannot be met leave no route
+        let tour_a_to_b = RouteConstraints { closed: true, start: Some(0), end: Some(1), ..RouteConstraints::default() };
+        assert_eq!(None, find_nodes(tour_a_to_b));
+        let a_to_a = RouteConstraints { start: Some(0), end: Some(0), ..RouteConstraints::default() };
+        assert_eq!(None, find_nodes(a_to_a));
+        let nothing = RouteConstraints { required: Some(vec![]), ..RouteConstraints::default() };
+        assert_eq!(None, find_nodes(nothing));
+        let just_a = RouteConstraints { closed: true, required: Some(vec![0]), ..RouteConstraints::default() };
+        assert_eq!(Some((vec![0], 0)), find_nodes(just_a));
+    }
+
+    #[test]
+    fn test_graph_held_karp_node_limit() {
+        // A path through 21 nodes is one more than Held-Karp accepts, which gives no route
+        let names = (0..21).map(|i| format!("N{}", i)).collect::<Vec<String>>();
+        let edges = names.windows(2).map(|pair| (pair[0].as_str(), pair[1].as_str(), 1)).collect::<Vec<(&str, &str, u64)>>();
+        let graph = build_graph(&edges, true);
+        let constraints = RouteConstraints::default();
+        assert_eq!(None, graph.find_hamiltonian_path(Objective::Shortest, &constraints, RouteAlgorithm::HeldKarp));
+        assert!(graph.find_all_hamiltonian_paths(Objective::Shortest, &constraints, RouteAlgorithm::HeldKarp).is_empty());
+        assert_eq!(Some(20), RouteAlgorithm::HeldKarp.get_max_nodes());
+        assert_eq!(None, RouteAlgorithm::BruteForce.get_max_nodes());
+        // Visiting only some of the nodes keeps the search within the limit
+        let constraints = RouteConstraints {
+            required: Some(vec![graph.get_node_id("N0").unwrap(), graph.get_node_id("N1").unwrap()]),
+            ..RouteConstraints::default()
+        };
+        let route = graph.find_hamiltonian_path(Objective::Shortest, &constraints, RouteAlgorithm::HeldKarp);
+        assert_eq!(Some(1), route.map(|route| route.get_distance()));
+    }
+
+    #[test]
+    fn test_graph_held_karp_matches_brute_force() {
+        // Pseudo-random graphs from a linear congruential generator, with some edges left out
+        let mut state: u64 = 12345;
+        let mut next_random = move || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            return state >> 33;
+        };
+        for count in 1..=7 {
+            let mut graph = WeightedGraph::new();
+            for node in 0..count {
+                graph.add_node(&node.to_string());
+            }
+            for from in 0..count {
+                for to in 0..count {
+                    if from != to && next_random() % 5 != 0 {
+                        graph.add_edge(from, to, next_random() % 100);
+                    }
+                }
+            }
+            let last = count - 1;
+            let constraints = [
+                RouteConstraints::default(),
+                closed_tour(),
+                RouteConstraints { start: Some(last), ..RouteConstraints::default() },
+                RouteConstraints { end: Some(0), ..RouteConstraints::default() },
+                RouteConstraints { start: Some(0), end: Some(last), ..RouteConstraints::default() },
+                RouteConstraints { closed: true, start: Some(last), ..RouteConstraints::default() },
+                RouteConstraints { required: Some((0..count).step_by(2).collect()), ..RouteConstraints::default() },
+                RouteConstraints { closed: true, required: Some(vec![last]), start: Some(0), ..RouteConstraints::default() },
+            ];
+            for objective in [Objective::Shortest, Objective::Longest].iter() {
+                for constraint in constraints.iter() {
+                    let brute_force = graph.find_hamiltonian_path(*objective, constraint, RouteAlgorithm::BruteForce);
+                    let held_karp = graph.find_hamiltonian_path(*objective, constraint, RouteAlgorithm::HeldKarp);
+                    let description = format!("{} nodes, {:?}, {:?}", count, objective, constraint);
+                    assert_eq!(brute_force.as_ref().map(|x| x.distance), held_karp.as_ref().map(|x| x.distance), "{}", description);
+                    // The route found must actually have the reported distance and meet the constraints
+                    if let Some(route) = held_karp {
+                        assert_eq!(Some(route.distance), graph.calculate_route_distance(&route.nodes), "{}", description);
+                        let mut expected = constraint.required.clone().unwrap_or_else(|| (0..count).collect());
+                        expected.extend(constraint.start.iter().chain(constraint.end.iter()));
+                        expected.sort();
+                        expected.dedup();
+                        let closing = if constraint.closed && expected.len() > 1 { 1 } else { 0 };
+                        assert_eq!(expected.len() + closing, route.nodes.len(), "{}", description);
+                        assert!(constraint.start.map_or(true, |start| route.nodes[0] == start), "{}", description);
+                        assert!(constraint.end.map_or(true, |end| *route.nodes.last().unwrap() == end), "{}", description);
+                    }
+                    // Both algorithms find exactly the same tied routes, and count the same ties when
+                    // only some are listed
+                    let all_routes = graph.find_all_hamiltonian_paths(*objective, constraint, RouteAlgorithm::BruteForce);
+                    assert_eq!(
+                        all_routes,
+                        graph.find_all_hamiltonian_paths(*objective, constraint, RouteAlgorithm::HeldKarp),
+                        "{}",
+                        description
+                    );
+                    for algorithm in [RouteAlgorithm::BruteForce, RouteAlgorithm::HeldKarp].iter() {
+                        let tied = graph.find_tied_hamiltonian_paths(*objective, constraint, *algorithm, 2);
+                        assert_eq!(all_routes.len() as u64, tied.get_count(), "{}", description);
+                        assert_eq!(all_routes.len().min(2), tied.get_routes().len(), "{}", description);
+                    }
+                }
+            }
+        }
+    }
+}
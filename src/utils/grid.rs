@@ -0,0 +1,324 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Index, IndexMut, Range};
+
+use crate::error::ParseError;
+use super::carto::{Neighbourhood, Point2D, Rect};
+use super::input::PuzzleInput;
+
+/// Two-dimensional grid of values with a fixed width and height, stored on the heap in row-major
+/// order. Locations are given as points with the origin at the top-left cell, x increasing to the
+/// right and y increasing downwards.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a new grid of the given size with every cell holding the given value.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width: width,
+            height: height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its rows, given from top to bottom. Returns `None` if the rows are not
+    /// all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        return Some(Self {
+            width: width,
+            height: height,
+            cells: rows.into_iter().flatten().collect::<Vec<T>>(),
+        });
+    }
+
+    /// Parses a grid from a character map in the puzzle input, with one row for each non-empty
+    /// line. Each character is converted into a cell value by the given function, which returns
+    /// `None` for characters that are not allowed. Errors are reported for the given day, with the
+    /// expected description used for disallowed characters.
+    pub fn parse_with<F>(input: &PuzzleInput, day: u32, expected: &str, f: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut rows: Vec<Vec<T>> = vec![];
+        let char_rows = input.get_grid(day)?;
+        let line_nums = input.get_lines().map(|(line_num, _)| line_num);
+        for (char_row, line_num) in char_rows.iter().zip(line_nums) {
+            let mut row: Vec<T> = vec![];
+            for (i, c) in char_row.iter().enumerate() {
+                match f(*c) {
+                    Some(value) => row.push(value),
+                    None => {
+                        let column = input.get_column(line_num, i);
+                        return Err(ParseError::new(day, line_num, column, &c.to_string(), expected));
+                    }
+                }
+            }
+            rows.push(row);
+        }
+        return Ok(Grid::from_rows(rows).unwrap());
+    }
+
+    /// Gets the number of columns in the grid.
+    pub fn get_width(&self) -> usize {
+        return self.width;
+    }
+
+    /// Gets the number of rows in the grid.
+    pub fn get_height(&self) -> usize {
+        return self.height;
+    }
+
+    /// Gets the rectangle covered by the grid, or `None` if the grid has no cells.
+    pub fn get_bounds(&self) -> Option<Rect> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        let bottom_right = Point2D::new(self.width as i64 - 1, self.height as i64 - 1);
+        return Some(Rect::new(Point2D::new(0, 0), bottom_right));
+    }
+
+    /// Checks if the point falls within the bounds of the grid.
+    pub fn contains(&self, point: &Point2D) -> bool {
+        return self.get_cell_index(point).is_some();
+    }
+
+    /// Gets the value held at the point, or `None` if the point is outside of the grid.
+    pub fn get(&self, point: &Point2D) -> Option<&T> {
+        return self.get_cell_index(point).map(|i| &self.cells[i]);
+    }
+
+    /// Gets a mutable reference to the value held at the point, or `None` if the point is outside
+    /// of the grid.
+    pub fn get_mut(&mut self, point: &Point2D) -> Option<&mut T> {
+        return self.get_cell_index(point).map(move |i| &mut self.cells[i]);
+    }
+
+    /// Gets the values in the given row, or `None` if the row is outside of the grid.
+    pub fn get_row(&self, y: usize) -> Option<&[T]> {
+        if y >= self.height {
+            return None;
+        }
+        return Some(&self.cells[y * self.width..(y + 1) * self.width]);
+    }
+
+    /// Iterates over every cell in reading order, giving the location and value of each cell.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2D, &T)> {
+        let width = self.width;
+        return self
+            .cells
+            .iter()
+            .enumerate()
+            .map(move |(i, value)| (Point2D::new((i % width) as i64, (i / width) as i64), value));
+    }
+
+    /// Iterates over the values held in every cell in reading order.
+    pub fn iter_values(&self) -> impl Iterator<Item = &T> {
+        return self.cells.iter();
+    }
+
+    /// Iterates over the rows of the grid, from top to bottom.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        // Chunks of zero length are not allowed, but a grid with no columns has no rows to give
+        return self.cells.chunks(self.width.max(1));
+    }
+
+    /// Iterates over the values in the given column, from top to bottom. The iterator is empty if
+    /// the column is outside of the grid.
+    pub fn iter_column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        return (0..height).map(move |y| &self.cells[y * self.width + x]);
+    }
+
+    /// Iterates over the cells in the region, in reading order. The region is clipped to the
+    /// bounds of the grid.
+    pub fn iter_region(&self, region: &Rect) -> impl Iterator<Item = (Point2D, &T)> {
+        let (x_range, y_range) = self.clip_region(region);
+        return y_range.flat_map(move |y| {
+            x_range
+                .clone()
+                .map(move |x| (Point2D::new(x as i64, y as i64), &self.cells[y * self.width + x]))
+        });
+    }
+
+    /// Iterates mutably over the cells in the region, in reading order. The region is clipped to
+    /// the bounds of the grid.
+    pub fn iter_region_mut(&mut self, region: &Rect) -> impl Iterator<Item = (Point2D, &mut T)> {
+        let (x_range, y_range) = self.clip_region(region);
+        return self
+            .cells
+            .chunks_mut(self.width.max(1))
+            .enumerate()
+            .skip(y_range.start)
+            .take(y_range.len())
+            .flat_map(move |(y, row)| {
+                let x_start = x_range.start;
+                row[x_range.clone()]
+                    .iter_mut()
+                    .enumerate()
+                    .map(move |(i, value)| (Point2D::new((x_start + i) as i64, y as i64), value))
+            });
+    }
+
+    /// Iterates over the neighbours of the point in the given neighbourhood that fall within the
+    /// grid, giving the location and value of each neighbour.
+    pub fn iter_neighbours(&self, point: &Point2D, neighbourhood: Neighbourhood) -> impl Iterator<Item = (Point2D, &T)> {
+        // A grid without cells has no bounds, so clip to the point itself, which is never one of
+        // its own neighbours
+        let bounds = self.get_bounds().unwrap_or_else(|| Rect::new(*point, *point));
+        return point
+            .iter_neighbours(neighbourhood)
+            .clip_to(bounds)
+            .map(move |neighbour| (neighbour, &self[neighbour]));
+    }
+
+    /// Renders the grid as text, with one line for each row and each cell converted into a
+    /// character by the given function.
+    pub fn render<F: Fn(&T) -> char>(&self, f: F) -> String {
+        let mut output = String::new();
+        for (y, row) in self.iter_rows().enumerate() {
+            if y > 0 {
+                output.push('\n');
+            }
+            output.extend(row.iter().map(&f));
+        }
+        return output;
+    }
+
+    /// Gets the index into the cells of the value held at the point, if within the grid.
+    fn get_cell_index(&self, point: &Point2D) -> Option<usize> {
+        let x = usize::try_from(point.get_x()).ok()?;
+        let y = usize::try_from(point.get_y()).ok()?;
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        return Some(y * self.width + x);
+    }
+
+    /// Clips the region to the bounds of the grid, giving the ranges of columns and rows within
+    /// the clipped region. The ranges are empty if the region is outside of the grid.
+    fn clip_region(&self, region: &Rect) -> (Range<usize>, Range<usize>) {
+        match self.get_bounds().and_then(|bounds| bounds.intersection(region)) {
+            Some(clipped) => {
+                let (top_left, bottom_right) = (clipped.get_top_left(), clipped.get_bottom_right());
+                return (
+                    top_left.get_x() as usize..bottom_right.get_x() as usize + 1,
+                    top_left.get_y() as usize..bottom_right.get_y() as usize + 1,
+                );
+            }
+            None => return (0..0, 0..0),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid from a character map in the puzzle input, keeping each character as is.
+    /// Errors are reported for the given day.
+    pub fn parse(input: &PuzzleInput, day: u32) -> Result<Self, ParseError> {
+        return Grid::parse_with(input, day, "any character", Some);
+    }
+}
+
+impl<T> Index<Point2D> for Grid<T> {
+    type Output = T;
+
+    /// Gets the value held at the point. Panics if the point is outside of the grid.
+    fn index(&self, point: Point2D) -> &T {
+        match self.get(&point) {
+            Some(value) => return value,
+            None => panic!("point {:?} is outside of the {}x{} grid", point, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point2D> for Grid<T> {
+    /// Gets a mutable reference to the value held at the point. Panics if the point is outside of
+    /// the grid.
+    fn index_mut(&mut self, point: Point2D) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(&point) {
+            Some(value) => return value,
+            None => panic!("point {:?} is outside of the {}x{} grid", point, width, height),
+        }
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(|c| *c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_access() {
+        let mut grid = Grid::new(3, 2, 0);
+        grid[Point2D::new(2, 1)] = 5;
+        *grid.get_mut(&Point2D::new(0, 1)).unwrap() += 2;
+        assert_eq!((3, 2), (grid.get_width(), grid.get_height()));
+        assert_eq!(Some(&5), grid.get(&Point2D::new(2, 1)));
+        assert_eq!(None, grid.get(&Point2D::new(3, 1)));
+        assert_eq!(None, grid.get(&Point2D::new(-1, 0)));
+        assert_eq!(Some(&[2, 0, 5][..]), grid.get_row(1));
+        assert_eq!(vec![&0, &5], grid.iter_column(2).collect::<Vec<&i32>>());
+        assert_eq!(0, grid.iter_column(3).count());
+        assert_eq!(Some((Point2D::new(0, 1), &2)), grid.iter().nth(3));
+        assert_eq!(7, grid.iter_values().sum::<i32>());
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+    }
+
+    #[test]
+    fn test_grid_regions() {
+        let mut grid = Grid::new(4, 4, false);
+        for (_, value) in grid.iter_region_mut(&Rect::new(Point2D::new(1, 1), Point2D::new(2, 5))) {
+            *value = true;
+        }
+        assert_eq!("....\n.##.\n.##.\n.##.", grid.render(|x| if *x { '#' } else { '.' }));
+        let points = grid
+            .iter_region(&Rect::new(Point2D::new(-2, 2), Point2D::new(1, 3)))
+            .map(|(point, _)| point)
+            .collect::<Vec<Point2D>>();
+        let expected = vec![Point2D::new(0, 2), Point2D::new(1, 2), Point2D::new(0, 3), Point2D::new(1, 3)];
+        assert_eq!(expected, points);
+        assert_eq!(0, grid.iter_region(&Rect::new(Point2D::new(4, 0), Point2D::new(6, 3))).count());
+        assert_eq!(None, Grid::new(0, 3, 0).get_bounds());
+        let neighbours = grid.iter_neighbours(&Point2D::new(0, 1), Neighbourhood::Moore);
+        assert_eq!(2, neighbours.filter(|(_, value)| **value).count());
+        assert_eq!(2, grid.iter_neighbours(&Point2D::new(3, 3), Neighbourhood::VonNeumann).count());
+    }
+
+    #[test]
+    fn test_grid_parse() {
+        let input = PuzzleInput::new("#.#\n..#\r\n");
+        let grid = Grid::parse(&input, 0).unwrap();
+        assert_eq!('#', grid[Point2D::new(2, 1)]);
+        assert_eq!("#.#\n..#", grid.to_string());
+        let lights = Grid::parse_with(&input, 0, "'#' or '.'", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(3, lights.iter_values().filter(|x| **x).count());
+        let err = Grid::parse_with(&PuzzleInput::new("#.\n.x"), 18, "'#' or '.'", |c| match c {
+            '#' | '.' => Some(c),
+            _ => None,
+        })
+        .unwrap_err();
+        assert_eq!((18, 2, 2), (err.get_day(), err.get_line(), err.get_column()));
+    }
+}
@@ -0,0 +1,162 @@
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+/// Raw puzzle input after normalisation, shared by the generators for every day.
+///
+/// Normalisation strips any leading byte order mark, converts CRLF (and lone CR) line endings to
+/// LF and removes trailing newlines, so the generators see the same text however the input file
+/// was saved. Line numbers given by the views are 1-based, matching `ParseError`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PuzzleInput {
+    text: String,
+}
+
+impl PuzzleInput {
+    /// Creates the normalised form of the raw puzzle input.
+    pub fn new(raw_input: &str) -> Self {
+        let text = raw_input.strip_prefix('\u{feff}').unwrap_or(raw_input);
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        Self {
+            text: text.trim_end_matches('\n').to_string(),
+        }
+    }
+
+    /// Gets the full normalised text of the input.
+    pub fn get_text(&self) -> &str {
+        return &self.text;
+    }
+
+    /// Gets the input as a single line, with leading and trailing whitespace removed. Used for
+    /// puzzles whose input is one value rather than a list.
+    pub fn get_line(&self) -> &str {
+        return self.text.trim();
+    }
+
+    /// Gets each non-empty line of the input along with its line number. Leading and trailing
+    /// whitespace is removed from each line, so positions within a line should be reported using
+    /// `get_column`.
+    pub fn get_lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        return self
+            .text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+    }
+
+    /// Gets the 1-based column in the input of the character at the given (0-based) index into a
+    /// line from `get_lines`, allowing for the leading whitespace removed from the line.
+    pub fn get_column(&self, line_num: usize, index: usize) -> usize {
+        let line = self.text.lines().nth(line_num - 1).unwrap_or("");
+        let indent = line.chars().take_while(|c| c.is_whitespace()).count();
+        return indent + index + 1;
+    }
+
+    /// Moves the column of an error found in a line from `get_lines` so that it counts from the
+    /// start of the line in the input, rather than from the start of the trimmed line.
+    pub fn locate_error(&self, err: ParseError) -> ParseError {
+        let column = self.get_column(err.get_line(), err.get_column() - 1);
+        return ParseError::new(err.get_day(), err.get_line(), column, err.get_text(), err.get_expected());
+    }
+
+    /// Gets the paragraphs of the input, being the groups of non-empty lines separated by one or
+    /// more blank lines. Each line is given along with its line number, as for `get_lines`.
+    pub fn get_paragraphs(&self) -> Vec<Vec<(usize, &str)>> {
+        let mut paragraphs: Vec<Vec<(usize, &str)>> = vec![];
+        let mut paragraph: Vec<(usize, &str)> = vec![];
+        for (i, line) in self.text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                if !paragraph.is_empty() {
+                    paragraphs.push(paragraph);
+                    paragraph = vec![];
+                }
+                continue;
+            }
+            paragraph.push((i + 1, line));
+        }
+        if !paragraph.is_empty() {
+            paragraphs.push(paragraph);
+        }
+        return paragraphs;
+    }
+
+    /// Gets the input as a grid of characters, with one row for each non-empty line. Returns an
+    /// error for the given day if the rows are not all the same length.
+    pub fn get_grid(&self, day: u32) -> Result<Vec<Vec<char>>, ParseError> {
+        let mut grid: Vec<Vec<char>> = vec![];
+        for (line_num, line) in self.get_lines() {
+            let row = line.chars().collect::<Vec<char>>();
+            if let Some(first_row) = grid.first() {
+                if row.len() != first_row.len() {
+                    let expected = format!("a grid row of {} characters", first_row.len());
+                    return Err(ParseError::new(day, line_num, self.get_column(line_num, 0), line, &expected));
+                }
+            }
+            grid.push(row);
+        }
+        return Ok(grid);
+    }
+
+    /// Gets every number in the input, with numbers separated by whitespace or commas. Returns an
+    /// error for the given day at the first value that cannot be parsed.
+    pub fn get_numbers<T: FromStr>(&self, day: u32) -> Result<Vec<T>, ParseError> {
+        let mut numbers: Vec<T> = vec![];
+        for (i, line) in self.text.lines().enumerate() {
+            let mut start = 0;
+            for field in line.split(|c: char| c == ',' || c.is_whitespace()) {
+                if !field.is_empty() {
+                    match field.parse::<T>() {
+                        Ok(value) => numbers.push(value),
+                        Err(_) => return Err(ParseError::new(day, i + 1, start + 1, field, "a number")),
+                    }
+                }
+                // Skip over the field and the separator following it
+                start += field.len() + 1;
+            }
+        }
+        return Ok(numbers);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_input_normalisation() {
+        let input = PuzzleInput::new("\u{feff}abc\r\ndef\rghi\n\n\n");
+        assert_eq!("abc\ndef\nghi", input.get_text());
+        assert_eq!("xyz", PuzzleInput::new("  xyz \r\n").get_line());
+        assert_eq!("", PuzzleInput::new("\n").get_text());
+    }
+
+    #[test]
+    fn test_puzzle_input_views() {
+        let input = PuzzleInput::new("ab\n cd \n\n\nef\n");
+        let lines = input.get_lines().collect::<Vec<(usize, &str)>>();
+        assert_eq!(vec![(1, "ab"), (2, "cd"), (5, "ef")], lines);
+        let paragraphs = input.get_paragraphs();
+        assert_eq!(vec![vec![(1, "ab"), (2, "cd")], vec![(5, "ef")]], paragraphs);
+        // Columns count the leading whitespace removed from each line
+        let input_with_indent = PuzzleInput::new("ab\n\t cd");
+        assert_eq!((1, 4), (input_with_indent.get_column(1, 0), input_with_indent.get_column(2, 1)));
+        let err = input_with_indent.locate_error(ParseError::new(3, 2, 2, "d", "a letter"));
+        assert_eq!((2, 4, "d"), (err.get_line(), err.get_column(), err.get_text()));
+        let grid = input.get_grid(0).unwrap();
+        assert_eq!(vec![vec!['a', 'b'], vec!['c', 'd'], vec!['e', 'f']], grid);
+        let err = PuzzleInput::new("ab\nc").get_grid(3).unwrap_err();
+        assert_eq!((3, 2, "c"), (err.get_day(), err.get_line(), err.get_text()));
+    }
+
+    #[test]
+    fn test_puzzle_input_numbers() {
+        let input = PuzzleInput::new("1, 2 3\r\n-4,5\n");
+        assert_eq!(vec![1, 2, 3, -4, 5], input.get_numbers::<i64>(0).unwrap());
+        let err = input.get_numbers::<u64>(7).unwrap_err();
+        assert_eq!((7, 2, 1, "-4"), (err.get_day(), err.get_line(), err.get_column(), err.get_text()));
+        let err = PuzzleInput::new("10 2x").get_numbers::<u64>(7).unwrap_err();
+        assert_eq!((1, 4, "2x"), (err.get_line(), err.get_column(), err.get_text()));
+    }
+}
@@ -1 +1,5 @@
-pub mod carto;
\ No newline at end of file
+pub mod carto;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod sparsegrid;
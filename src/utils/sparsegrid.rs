@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use super::carto::{Point2D, Rect};
+
+/// Two-dimensional grid of values with no fixed size, stored in a hash map keyed by location.
+/// Only occupied cells are stored, while every other cell holds the default value. Uses the same
+/// orientation as `Grid`: x increases to the right and y increases downwards.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2D, T>,
+    default: T,
+    bounds: Option<Rect>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates a new empty grid, where every cell holds the given default value.
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default: default,
+            bounds: None,
+        }
+    }
+
+    /// Gets the number of occupied cells.
+    pub fn len(&self) -> usize {
+        return self.cells.len();
+    }
+
+    /// Checks if no cells are occupied.
+    pub fn is_empty(&self) -> bool {
+        return self.cells.is_empty();
+    }
+
+    /// Gets the smallest rectangle holding every occupied cell, or `None` if the grid is empty.
+    pub fn get_bounds(&self) -> Option<Rect> {
+        return self.bounds;
+    }
+
+    /// Checks if the cell at the point is occupied.
+    pub fn contains(&self, point: &Point2D) -> bool {
+        return self.cells.contains_key(point);
+    }
+
+    /// Gets the value held at the point, which is the default value for unoccupied cells.
+    pub fn get(&self, point: &Point2D) -> &T {
+        return self.cells.get(point).unwrap_or(&self.default);
+    }
+
+    /// Updates the value held at the point, returning the previous value if the cell was occupied.
+    pub fn insert(&mut self, point: Point2D, value: T) -> Option<T> {
+        self.expand_bounds(&point);
+        return self.cells.insert(point, value);
+    }
+
+    /// Clears the cell at the point, returning its value if the cell was occupied.
+    pub fn remove(&mut self, point: &Point2D) -> Option<T> {
+        let value = self.cells.remove(point)?;
+        // Only a cell on the edge of the bounding box can shrink it
+        if let Some(bounds) = self.bounds {
+            let (top_left, bottom_right) = (bounds.get_top_left(), bounds.get_bottom_right());
+            if point.get_x() == top_left.get_x()
+                || point.get_x() == bottom_right.get_x()
+                || point.get_y() == top_left.get_y()
+                || point.get_y() == bottom_right.get_y()
+            {
+                self.bounds = None;
+                let points = self.cells.keys().copied().collect::<Vec<Point2D>>();
+                for point in points.iter() {
+                    self.expand_bounds(point);
+                }
+            }
+        }
+        return Some(value);
+    }
+
+    /// Iterates over the location and value of each occupied cell, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2D, &T)> {
+        return self.cells.iter().map(|(point, value)| (*point, value));
+    }
+
+    /// Renders the occupied region as text, with one line for each row and each cell (occupied
+    /// or not) converted into a character by the given function. An empty grid renders as an
+    /// empty string.
+    pub fn render<F: Fn(&T) -> char>(&self, f: F) -> String {
+        let bounds = match self.bounds {
+            Some(bounds) => bounds,
+            None => return String::new(),
+        };
+        let mut output = String::new();
+        for y in bounds.get_top_left().get_y()..=bounds.get_bottom_right().get_y() {
+            if y > bounds.get_top_left().get_y() {
+                output.push('\n');
+            }
+            for x in bounds.get_top_left().get_x()..=bounds.get_bottom_right().get_x() {
+                output.push(f(self.get(&Point2D::new(x, y))));
+            }
+        }
+        return output;
+    }
+
+    /// Grows the bounding box to hold the point.
+    fn expand_bounds(&mut self, point: &Point2D) {
+        self.bounds = match self.bounds {
+            Some(bounds) if bounds.contains(point) => Some(bounds),
+            Some(bounds) => Some(Rect::new(
+                Point2D::new(bounds.get_top_left().get_x().min(point.get_x()), bounds.get_top_left().get_y().min(point.get_y())),
+                Point2D::new(bounds.get_bottom_right().get_x().max(point.get_x()), bounds.get_bottom_right().get_y().max(point.get_y())),
+            )),
+            None => Some(Rect::new(*point, *point)),
+        };
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Gets a mutable reference to the value held at the point, occupying the cell with the
+    /// default value first if needed.
+    pub fn get_mut(&mut self, point: &Point2D) -> &mut T {
+        self.expand_bounds(point);
+        let default = &self.default;
+        return self.cells.entry(*point).or_insert_with(|| default.clone());
+    }
+}
+
+impl SparseGrid<u64> {
+    /// Creates a new grid of counters, all starting at zero.
+    pub fn new_counter() -> Self {
+        return SparseGrid::new(0);
+    }
+
+    /// Adds one to the counter at the point, returning its new value.
+    pub fn increment(&mut self, point: &Point2D) -> u64 {
+        let count = self.get_mut(point);
+        *count += 1;
+        return *count;
+    }
+}
+
+impl fmt::Display for SparseGrid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(|c| *c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_grid_counters() {
+        let mut grid = SparseGrid::new_counter();
+        assert_eq!(None, grid.get_bounds());
+        assert_eq!(1, grid.increment(&Point2D::new(-2, 3)));
+        assert_eq!(2, grid.increment(&Point2D::new(-2, 3)));
+        grid.increment(&Point2D::new(4, -1));
+        assert_eq!(2, grid.len());
+        assert_eq!(0, *grid.get(&Point2D::new(0, 0)));
+        assert!(!grid.contains(&Point2D::new(0, 0)));
+        assert_eq!(Some(Rect::new(Point2D::new(-2, -1), Point2D::new(4, 3))), grid.get_bounds());
+        assert_eq!(3, grid.iter().map(|(_, count)| count).sum::<u64>());
+        // Removing an edge cell shrinks the bounding box
+        assert_eq!(Some(1), grid.remove(&Point2D::new(4, -1)));
+        assert_eq!(None, grid.remove(&Point2D::new(4, -1)));
+        assert_eq!(Some(Rect::new(Point2D::new(-2, 3), Point2D::new(-2, 3))), grid.get_bounds());
+    }
+
+    #[test]
+    fn test_sparse_grid_render() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!("", grid.to_string());
+        grid.insert(Point2D::new(-1, -1), '#');
+        grid.insert(Point2D::new(1, 0), '#');
+        assert_eq!(Some('#'), grid.insert(Point2D::new(1, 0), '@'));
+        assert_eq!("#..\n..@", grid.to_string());
+        assert_eq!("x..\n..x", grid.render(|c| if *c == '.' { '.' } else { 'x' }));
+    }
+}
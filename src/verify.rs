@@ -0,0 +1,265 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+use super::error::AocError;
+use super::profile::get_profile_dir;
+use super::registry::get_days;
+use super::runner::{get_input_path, read_input, run_day, RunOptions};
+use super::solution::Answer;
+
+/// Database of known puzzle answers, keyed by the profile whose puzzle input each answer
+/// belongs to.
+///
+/// Stored as text, with a `[profile]` heading followed by one `<day> <part> <answer>` line for
+/// each known answer. Blank lines and lines starting with `#` are ignored.
+#[derive(Clone, Debug, Default)]
+pub struct AnswerDb {
+    answers: BTreeMap<String, BTreeMap<(u32, u32), String>>,
+}
+
+impl AnswerDb {
+    /// Creates a new empty answer database.
+    pub fn new() -> Self {
+        Self {
+            answers: BTreeMap::new(),
+        }
+    }
+
+    /// Loads the answer database from the given file.
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        return AnswerDb::parse(&read_input(path)?);
+    }
+
+    /// Parses the answer database from its text form.
+    pub fn parse(text: &str) -> Result<Self, AocError> {
+        let mut db = AnswerDb::new();
+        let mut profile: Option<String> = None;
+        for (line_num, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            // Check for heading giving the profile the following answers belong to
+            if line.starts_with('[') && line.ends_with(']') && line.len() > 2 {
+                profile = Some(line[1..line.len() - 1].trim().to_string());
+                continue;
+            }
+            let fields = line.splitn(3, ' ').collect::<Vec<&str>>();
+            let (profile, day, part, answer) = match (&profile, fields.as_slice()) {
+                (Some(profile), [day, part, answer]) => match (day.parse::<u32>(), part.parse::<u32>()) {
+                    (Ok(day), Ok(part)) if part == 1 || part == 2 => (profile, day, part, answer.trim()),
+                    _ => return Err(AocError::InvalidAnswerLine(line_num + 1, line.to_string())),
+                },
+                _ => return Err(AocError::InvalidAnswerLine(line_num + 1, line.to_string())),
+            };
+            db.insert_answer(profile, day, part, answer);
+        }
+        return Ok(db);
+    }
+
+    /// Records the answer for the given profile, day and part, replacing any existing answer.
+    pub fn insert_answer(&mut self, profile: &str, day: u32, part: u32, answer: &str) {
+        self.answers
+            .entry(profile.to_string())
+            .or_default()
+            .insert((day, part), answer.to_string());
+    }
+
+    /// Gets the known answer for the given profile, day and part.
+    pub fn get_answer(&self, profile: &str, day: u32, part: u32) -> Option<&str> {
+        return self
+            .answers
+            .get(profile)
+            .and_then(|answers| answers.get(&(day, part)))
+            .map(|answer| answer.as_str());
+    }
+
+    /// Gets the names of the profiles with known answers.
+    pub fn get_profiles(&self) -> Vec<&str> {
+        return self.answers.keys().map(|profile| profile.as_str()).collect::<Vec<&str>>();
+    }
+}
+
+/// Result of checking the answer to one part of a puzzle against the known answer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer matches the known answer.
+    Pass,
+    /// The answer differs from the known answer.
+    Fail,
+    /// There is no known answer to check against, no input to find an answer from, or no solution
+    /// yet for the part.
+    Missing,
+    /// No answer could be found.
+    Error,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail => "FAIL",
+            Verdict::Missing => "MISSING",
+            Verdict::Error => "ERROR",
+        };
+        return f.pad(text);
+    }
+}
+
+/// Verification of the answer found for one part of a day's puzzle.
+pub struct PartVerification {
+    pub profile: String,
+    pub day: u32,
+    pub part: u32,
+    pub verdict: Verdict,
+    pub answer: Option<Answer>,
+    pub expected: Option<String>,
+    /// Description of the error that stopped an answer from being found.
+    pub error: Option<String>,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Solves both parts of the day's puzzle, run with the given options, and checks the answers
+/// against the known answers for the given profile.
+pub fn verify_day(
+    answers: &AnswerDb,
+    profile: &str,
+    day: u32,
+    raw_input: &str,
+    options: &RunOptions,
+) -> Vec<PartVerification> {
+    let outcome = run_day(day, &[1, 2], raw_input, options);
+    let mut verifications: Vec<PartVerification> = vec![];
+    for part in 1..=2 {
+        let expected = answers.get_answer(profile, day, part).map(|answer| answer.to_string());
+        let mut verification = PartVerification {
+            profile: profile.to_string(),
+            day: day,
+            part: part,
+            verdict: Verdict::Error,
+            answer: None,
+            expected: expected,
+            error: None,
+            parse_time: outcome.parse_time,
+            solve_time: Duration::ZERO,
+        };
+        match &outcome.parts {
+            Ok(parts) => {
+                let part_outcome = &parts[part as usize - 1];
+                verification.solve_time = part_outcome.solve_time;
+                match &part_outcome.answer {
+                    Ok(answer) => {
+                        verification.verdict = match &verification.expected {
+                            Some(expected) if *expected == answer.to_string() => Verdict::Pass,
+                            Some(_) => Verdict::Fail,
+                            None => Verdict::Missing,
+                        };
+                        verification.answer = Some(answer.clone());
+                    }
+                    Err(err) => {
+                        // Parts without a solution yet have nothing to check, rather than failing
+                        if let AocError::NotImplemented(_, _) = err {
+                            verification.verdict = Verdict::Missing;
+                        }
+                        verification.error = Some(err.to_string());
+                    }
+                }
+            }
+            Err(err) => verification.error = Some(err.to_string()),
+        }
+        verifications.push(verification);
+    }
+    return verifications;
+}
+
+/// Verifies every registered day using the puzzle inputs held for the profile under the input
+/// root directory. Days without an input for the profile are reported as missing.
+pub fn verify_profile(
+    answers: &AnswerDb,
+    profile: &str,
+    input_root: &Path,
+    options: &RunOptions,
+) -> Vec<PartVerification> {
+    let input_dir = get_profile_dir(input_root, profile);
+    let mut verifications: Vec<PartVerification> = vec![];
+    for day in get_days() {
+        let input_path = get_input_path(&input_dir, day);
+        let input = if input_path.exists() {
+            read_input(&input_path)
+        } else {
+            Err(AocError::MissingInput(input_path))
+        };
+        match input {
+            Ok(raw_input) => verifications.append(&mut verify_day(answers, profile, day, &raw_input, options)),
+            Err(err) => {
+                // Without an input, neither part can be solved
+                let verdict = match err {
+                    AocError::MissingInput(_) => Verdict::Missing,
+                    _ => Verdict::Error,
+                };
+                for part in 1..=2 {
+                    verifications.push(PartVerification {
+                        profile: profile.to_string(),
+                        day: day,
+                        part: part,
+                        verdict: verdict,
+                        answer: None,
+                        expected: answers.get_answer(profile, day, part).map(|answer| answer.to_string()),
+                        error: Some(err.to_string()),
+                        parse_time: Duration::ZERO,
+                        solve_time: Duration::ZERO,
+                    });
+                }
+            }
+        }
+    }
+    return verifications;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "\
+# Test answers
+[default]
+1 1 232
+1 2 1783
+
+[alice]
+1 1 -1
+11 1 hepxxyzz
+";
+
+    #[test]
+    fn test_answer_db_parse() {
+        let db = AnswerDb::parse(ANSWERS).unwrap();
+        assert_eq!(vec!["alice", "default"], db.get_profiles());
+        assert_eq!(Some("1783"), db.get_answer("default", 1, 2));
+        assert_eq!(Some("hepxxyzz"), db.get_answer("alice", 11, 1));
+        assert_eq!(None, db.get_answer("alice", 1, 2));
+        assert!(matches!(AnswerDb::parse("1 1 232"), Err(AocError::InvalidAnswerLine(1, _))));
+        assert!(matches!(AnswerDb::parse("[a]\n1 3 232"), Err(AocError::InvalidAnswerLine(2, _))));
+    }
+
+    #[test]
+    fn test_verify_day() {
+        let db = AnswerDb::parse(ANSWERS).unwrap();
+        let options = RunOptions::default();
+        let verifications = verify_day(&db, "alice", 1, "())", &options);
+        assert_eq!(Verdict::Pass, verifications[0].verdict);
+        assert_eq!(Verdict::Missing, verifications[1].verdict);
+        let verifications = verify_day(&db, "default", 1, "())", &options);
+        assert_eq!(Verdict::Fail, verifications[0].verdict);
+        assert_eq!(Verdict::Fail, verifications[1].verdict);
+        let verifications = verify_day(&db, "default", 1, "(x)", &options);
+        assert_eq!(Verdict::Error, verifications[0].verdict);
+        assert!(verifications[0].error.is_some());
+        let verifications = verify_day(&db, "alice", 11, "hepxcrrq", &options);
+        assert_eq!(Verdict::Missing, verifications[0].verdict);
+        assert_eq!(Some(String::from("day 11 part 1 is not implemented yet")), verifications[0].error);
+    }
+}
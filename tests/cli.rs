@@ -0,0 +1,65 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Runs the aoc2015 binary with the given arguments, feeding the input to stdin. Gives the full
+/// stdout from the run.
+fn run_aoc2015(args: &[&str], input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc2015"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to start aoc2015");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    return String::from_utf8(output.stdout).expect("stdout is not UTF-8");
+}
+
+#[test]
+fn test_cli_json_stdout() {
+    // Nothing but the report reaches stdout, with each result on its own line ahead of its timings
+    let stdout = run_aoc2015(&["1", "--format", "json", "--input", "-"], "(()))\n");
+    let lines = stdout.lines().collect::<Vec<&str>>();
+    assert_eq!(7, lines.len(), "stdout: {}", stdout);
+    assert_eq!(["{", "  \"results\": ["], lines[..2]);
+    assert!(lines[2].starts_with(
+        "    {\"profile\": null, \"day\": 1, \"part\": 1, \"status\": \"ok\", \"answer\": \"-1\", \"expected\": null, \
+         \"error\": null, \"parse_time\": "
+    ));
+    assert!(lines[3].starts_with(
+        "    {\"profile\": null, \"day\": 1, \"part\": 2, \"status\": \"ok\", \"answer\": \"5\", \"expected\": null, \
+         \"error\": null, \"parse_time\": "
+    ));
+    let summary = "  \"summary\": {\"total\": 2, \"ok\": 2, \"pass\": 0, \"fail\": 0, \"missing\": 0, \"error\": 0}";
+    assert_eq!(["  ],", summary, "}"], lines[4..]);
+    // Day 11 has no solution yet, which is reported as missing
+    let stdout = run_aoc2015(&["11", "--format", "json", "--input", "-"], "hepxcrrq\n");
+    let lines = stdout.lines().collect::<Vec<&str>>();
+    assert_eq!(7, lines.len(), "stdout: {}", stdout);
+    assert!(lines[2].contains(
+        "\"status\": \"missing\", \"answer\": null, \"expected\": null, \
+         \"error\": \"day 11 part 1 is not implemented yet\""
+    ));
+    let summary = "  \"summary\": {\"total\": 2, \"ok\": 0, \"pass\": 0, \"fail\": 0, \"missing\": 2, \"error\": 0}";
+    assert_eq!(summary, lines[5]);
+}
+
+#[test]
+fn test_cli_junit_stdout() {
+    let stdout = run_aoc2015(&["11", "--format", "junit", "--input", "-"], "hepxcrrq\n");
+    let lines = stdout.lines().collect::<Vec<&str>>();
+    assert_eq!(7, lines.len(), "stdout: {}", stdout);
+    assert_eq!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>", lines[0]);
+    assert!(lines[1].starts_with("<testsuites name=\"aoc2015\" tests=\"2\" failures=\"0\" errors=\"0\" skipped=\"2\" "));
+    assert!(lines[3].ends_with("><skipped message=\"day 11 part 1 is not implemented yet\"/></testcase>"));
+    assert_eq!(["  </testsuite>", "</testsuites>"], lines[5..]);
+}
+
+#[test]
+fn test_cli_verify_shipped_answers() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc2015")).arg("verify").output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "stdout: {}", stdout);
+    assert!(stdout.contains("20 passed, 0 failed, 2 missing, 0 errors"), "stdout: {}", stdout);
+}